@@ -0,0 +1,226 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{AccessCommands, Permission},
+    gitea_client::{authenticated_client, GiteaClient},
+    models::{Repository, Team, User},
+    output::print_json,
+    prompt::confirm,
+    repo_context::{resolve_server_args, RepoContext},
+    table::Table,
+};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+// Who has access to a repository, and how much
+#[derive(Serialize)]
+struct Access {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository: Option<String>,
+    kind: &'static str,
+    name: String,
+    permission: String,
+}
+
+#[derive(Deserialize)]
+struct CollaboratorPermission {
+    permission: String,
+}
+
+#[derive(Serialize)]
+struct AddCollaboratorOption {
+    permission: &'static str,
+}
+
+pub(crate) async fn access(command: &AccessCommands) -> Result<(), AppError> {
+    match command {
+        AccessCommands::List { repo_args, json } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let access =
+                get_access(&gitea_client, repo_context.api_path("").as_str(), None).await?;
+            if *json {
+                print_json(&access)
+            } else if access.is_empty() {
+                crate::print_info!("Only the owner has access to {}", repo_context.repo());
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Kind", "Permission", "Name"]);
+                for entry in access.iter() {
+                    table.add_row(vec![
+                        entry.kind.to_string(),
+                        entry.permission.clone(),
+                        entry.name.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        AccessCommands::Add {
+            repo_args,
+            name,
+            permission,
+            team,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            if *team {
+                gitea_client
+                    .put(
+                        repo_context
+                            .api_path(format!("/teams/{}", name).as_str())
+                            .as_str(),
+                        &serde_json::json!({}),
+                    )
+                    .await?;
+                crate::print_success!("Gave team {} access to {}", name, repo_context.repo());
+            } else {
+                let permission = permission_str(permission.as_ref().unwrap_or(&Permission::Write));
+                gitea_client
+                    .put(
+                        repo_context
+                            .api_path(format!("/collaborators/{}", name).as_str())
+                            .as_str(),
+                        &AddCollaboratorOption { permission },
+                    )
+                    .await?;
+                crate::print_success!(
+                    "Gave {} {} access to {}",
+                    name,
+                    permission,
+                    repo_context.repo()
+                );
+            }
+            Ok(())
+        }
+        AccessCommands::Remove {
+            repo_args,
+            name,
+            team,
+            yes,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let (kind, path) = if *team {
+                ("team", format!("/teams/{}", name))
+            } else {
+                ("collaborator", format!("/collaborators/{}", name))
+            };
+            crate::print_info!("Removing {} {} from {}", kind, name, repo_context.repo());
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(repo_context.api_path(path.as_str()).as_str())
+                .await?;
+            crate::print_success!("Removed {} {} from {}", kind, name, repo_context.repo());
+            Ok(())
+        }
+        AccessCommands::Audit {
+            server_args,
+            org,
+            csv,
+            json,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let repositories = gitea_client
+                .get_all::<Repository>(format!("orgs/{}/repos", org).as_str(), &[])
+                .await?;
+            let mut access = Vec::new();
+            for repository in repositories.iter() {
+                let mut repo_access = get_access(
+                    &gitea_client,
+                    format!("repos/{}", repository.full_name).as_str(),
+                    Some(repository.full_name.as_str()),
+                )
+                .await?;
+                access.append(&mut repo_access);
+            }
+            if *json {
+                return print_json(&access);
+            }
+            let mut table = Table::new(&["Repository", "Kind", "Permission", "Name"]);
+            for entry in access.into_iter() {
+                table.add_row(vec![
+                    entry.repository.unwrap_or_default(),
+                    entry.kind.to_string(),
+                    entry.permission,
+                    entry.name,
+                ]);
+            }
+            if *csv {
+                table.print_csv();
+            } else {
+                crate::print_info!("{} repositories in {}", repositories.len(), org);
+                table.print();
+            }
+            Ok(())
+        }
+    }
+}
+
+// The collaborators and teams with access to the repository at the API path
+async fn get_access(
+    gitea_client: &GiteaClient,
+    repo_path: &str,
+    repository: Option<&str>,
+) -> Result<Vec<Access>, AppError> {
+    let mut access = Vec::new();
+    let collaborators = gitea_client
+        .get_all::<User>(format!("{}/collaborators", repo_path).as_str(), &[])
+        .await?;
+    for collaborator in collaborators.into_iter() {
+        let permission = gitea_client
+            .get::<CollaboratorPermission>(
+                format!(
+                    "{}/collaborators/{}/permission",
+                    repo_path, collaborator.login
+                )
+                .as_str(),
+                &[],
+            )
+            .await?;
+        access.push(Access {
+            repository: repository.map(String::from),
+            kind: "user",
+            name: collaborator.login,
+            permission: permission.permission,
+        });
+    }
+    // Only repositories owned by an organization have teams. Gitea says so with
+    // 405 Method Not Allowed, or 404 Not Found from older versions; anything
+    // else, e.g. not being allowed to see the teams, is an error.
+    let teams = match gitea_client
+        .get::<Vec<Team>>(format!("{}/teams", repo_path).as_str(), &[])
+        .await
+    {
+        Ok(teams) => teams,
+        Err(AppError::Status(StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED, _)) => {
+            Vec::new()
+        }
+        Err(error) => return Err(error),
+    };
+    for team in teams.into_iter() {
+        access.push(Access {
+            repository: repository.map(String::from),
+            kind: "team",
+            name: team.name,
+            permission: team.permission,
+        });
+    }
+    Ok(access)
+}
+
+pub(crate) fn permission_str(permission: &Permission) -> &'static str {
+    match permission {
+        Permission::Read => "read",
+        Permission::Write => "write",
+        Permission::Admin => "admin",
+    }
+}
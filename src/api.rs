@@ -0,0 +1,234 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::ApiArgs,
+    gitea_client::{authenticated_client, GiteaClient},
+    output::print_json,
+    repo_context::{resolve_server, RepoContext},
+};
+use reqwest::Method;
+use serde_json::{Map, Value};
+use std::io::Read;
+use std::path::Path;
+
+pub(crate) async fn api(api_args: &ApiArgs) -> Result<(), AppError> {
+    let method = Method::from_bytes(api_args.method.to_uppercase().as_bytes())
+        .map_err(|_| AppError::from(format!("Invalid HTTP method '{}'", api_args.method)))?;
+    // The path may be copied from the API documentation, with or without the prefix
+    let path = api_args.endpoint.trim_start_matches('/');
+    let path = path.strip_prefix("api/v1/").unwrap_or(path);
+    // Only look for the repository if the path needs it
+    let (gitea_url, path) = if path.contains("{owner}") || path.contains("{repo}") {
+        let repo_context = RepoContext::resolve(&api_args.repo_args)?;
+        let path = path
+            .replace("{owner}", repo_context.repo().owner())
+            .replace("{repo}", repo_context.repo().name());
+        (repo_context.gitea_url().to_owned(), path)
+    } else {
+        (resolve_server(&api_args.repo_args)?, path.to_string())
+    };
+    let gitea_client = authenticated_client(&gitea_url).await?;
+
+    let fields = fields(api_args)?;
+    let (query, body) = match &api_args.input {
+        Some(input) => (fields, Some(read_input(input)?)),
+        None if method == Method::GET || method == Method::HEAD => (fields, None),
+        None if fields.is_empty() => (Vec::new(), None),
+        None => (Vec::new(), Some(json_body(&fields)?)),
+    };
+    let query = query
+        .iter()
+        .map(|(key, value)| (key.as_str(), value_str(value)))
+        .collect::<Vec<(&str, String)>>();
+
+    if api_args.paginate {
+        if method != Method::GET {
+            return Err(AppError::from("Only GET requests can be paginated"));
+        }
+        let value = get_all_pages(&gitea_client, path.as_str(), &query).await?;
+        return print_value(&value, api_args.raw);
+    }
+    let text = gitea_client
+        .send_text(method, path.as_str(), &query, body)
+        .await?;
+    if text.is_empty() {
+        Ok(())
+    } else if api_args.raw {
+        println!("{}", text);
+        Ok(())
+    } else {
+        match serde_json::from_str::<Value>(text.as_str()) {
+            Ok(value) => print_value(&value, false),
+            // Not everything is JSON, e.g. diffs
+            Err(_) => {
+                println!("{}", text);
+                Ok(())
+            }
+        }
+    }
+}
+
+// Only an endpoint that says how many items there are is paged through, since
+// some lists ignore the page. The items are a list, or the data list in an
+// object, e.g. from repos/search; anything else is returned as it is.
+async fn get_all_pages(
+    gitea_client: &GiteaClient,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<Value, AppError> {
+    let (mut value, total_count) = gitea_client.get_page::<Value>(path, query, 1).await?;
+    let Some(total_count) = total_count else {
+        return Ok(value);
+    };
+    let mut page = 1;
+    loop {
+        let Some(items) = page_items(&mut value) else {
+            return Ok(value);
+        };
+        if items.len() >= total_count {
+            return Ok(value);
+        }
+        page += 1;
+        let (mut next, _) = gitea_client.get_page::<Value>(path, query, page).await?;
+        match page_items(&mut next) {
+            Some(next_items) if !next_items.is_empty() => items.append(next_items),
+            _ => return Ok(value),
+        }
+    }
+}
+
+fn page_items(value: &mut Value) -> Option<&mut Vec<Value>> {
+    match value {
+        Value::Array(items) => Some(items),
+        Value::Object(object) => match object.get_mut("data") {
+            Some(Value::Array(items)) => Some(items),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The --field values are strings, and the --typed-field values are JSON
+fn fields(api_args: &ApiArgs) -> Result<Vec<(String, Value)>, AppError> {
+    let mut fields = Vec::new();
+    for field in api_args.fields.iter() {
+        let (key, value) = split_field(field)?;
+        fields.push((key.to_string(), Value::String(value.to_string())));
+    }
+    for field in api_args.typed_fields.iter() {
+        let (key, value) = split_field(field)?;
+        let value = serde_json::from_str::<Value>(value)
+            .unwrap_or_else(|_| Value::String(value.to_string()));
+        fields.push((key.to_string(), value));
+    }
+    Ok(fields)
+}
+
+fn split_field(field: &str) -> Result<(&str, &str), AppError> {
+    field
+        .split_once('=')
+        .ok_or_else(|| AppError::from(format!("'{}' is not KEY=VALUE", field)))
+}
+
+// A key ending with [] collects its values in a list, e.g. labels[]=1 labels[]=2
+fn json_body(fields: &[(String, Value)]) -> Result<Vec<u8>, AppError> {
+    let mut object = Map::new();
+    for (key, value) in fields.iter() {
+        match key.strip_suffix("[]") {
+            Some(key) => {
+                let list = object
+                    .entry(key.to_string())
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                match list {
+                    Value::Array(list) => list.push(value.clone()),
+                    _ => return Err(AppError::from(format!("'{}' is not a list", key))),
+                }
+            }
+            None => {
+                object.insert(key.to_string(), value.clone());
+            }
+        }
+    }
+    serde_json::to_vec(&object).map_err(|error| AppError::from(error.to_string()))
+}
+
+fn read_input(input: &Path) -> Result<Vec<u8>, AppError> {
+    if input == Path::new("-") {
+        let mut bytes = Vec::new();
+        std::io::stdin().read_to_end(&mut bytes)?;
+        Ok(bytes)
+    } else {
+        Ok(std::fs::read(input)?)
+    }
+}
+
+// Query string values are the plain text of strings, and JSON of anything else
+fn value_str(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.to_owned(),
+        value => value.to_string(),
+    }
+}
+
+fn print_value(value: &Value, raw: bool) -> Result<(), AppError> {
+    if raw {
+        println!("{}", value);
+        Ok(())
+    } else {
+        print_json(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_body, split_field};
+    use serde_json::{json, Value};
+
+    fn body(fields: &[(&str, Value)]) -> Value {
+        let fields = fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect::<Vec<(String, Value)>>();
+        serde_json::from_slice(&json_body(&fields).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn fields_split_at_the_first_equals_sign() {
+        assert_eq!(split_field("title=a=b").unwrap(), ("title", "a=b"));
+        assert_eq!(split_field("body=").unwrap(), ("body", ""));
+        assert!(split_field("title").is_err());
+    }
+
+    #[test]
+    fn fields_make_an_object() {
+        assert_eq!(
+            body(&[("title", json!("Bug")), ("closed", json!(true))]),
+            json!({"title": "Bug", "closed": true})
+        );
+    }
+
+    #[test]
+    fn list_fields_collect_their_values() {
+        assert_eq!(
+            body(&[
+                ("labels[]", json!(1)),
+                ("title", json!("Bug")),
+                ("labels[]", json!(2))
+            ]),
+            json!({"labels": [1, 2], "title": "Bug"})
+        );
+    }
+
+    #[test]
+    fn a_list_field_cannot_add_to_a_value() {
+        let fields = [
+            (String::from("labels"), json!(1)),
+            (String::from("labels[]"), json!(2)),
+        ];
+        assert!(json_body(&fields).is_err());
+    }
+}
@@ -6,20 +6,36 @@ use std::fmt::{Debug, Display, Formatter};
 
 pub enum AppError {
     Url(url::ParseError),
-    Api(gitea_api::api_error::ApiError),
     Parameter(crate::create::parameters_error::ParametersError),
     Other(String),
+    // The user said no, or gave no answer, at a prompt
+    Canceled,
     Io(std::io::Error),
+    Request(reqwest::Error),
+    Status(reqwest::StatusCode, String),
+    Git(String),
+    // A label, team, file, etc. named on the command line doesn't exist
+    NotFound(String),
 }
 
 impl Display for AppError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let str = match self {
             AppError::Url(error) => error.to_string(),
-            AppError::Api(error) => error.to_string(),
             AppError::Parameter(error) => error.to_string(),
             AppError::Other(error) => error.to_string(),
+            AppError::Canceled => String::from("Canceled"),
             AppError::Io(error) => error.to_string(),
+            AppError::Request(error) => error.to_string(),
+            AppError::Git(error) => error.to_string(),
+            AppError::NotFound(error) => error.to_string(),
+            AppError::Status(status, message) => {
+                if message.is_empty() {
+                    status.to_string()
+                } else {
+                    format!("{}: {}", status, message)
+                }
+            }
         };
         write!(f, "{}", str)
     }
@@ -31,12 +47,6 @@ impl Debug for AppError {
     }
 }
 
-impl From<gitea_api::api_error::ApiError> for AppError {
-    fn from(err: gitea_api::api_error::ApiError) -> AppError {
-        AppError::Api(err)
-    }
-}
-
 impl From<crate::create::parameters_error::ParametersError> for AppError {
     fn from(err: crate::create::parameters_error::ParametersError) -> AppError {
         AppError::Parameter(err)
@@ -66,3 +76,9 @@ impl From<std::io::Error> for AppError {
         AppError::Io(err)
     }
 }
+
+impl From<reqwest::Error> for AppError {
+    fn from(err: reqwest::Error) -> AppError {
+        AppError::Request(err)
+    }
+}
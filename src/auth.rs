@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{AuthCommands, TokenStorage},
+    config::{config_file, same_server, Config},
+    credentials::{token_and_source, token_option},
+    git::credential,
+    gitea_client::{password_client, GiteaClient},
+    models::User,
+    oauth::{self, set_token},
+    repo_context::resolve_server_args,
+    token_store::{remove_token, store_file, store_token, stored_servers},
+};
+use url::Url;
+
+pub(crate) async fn auth(command: &AuthCommands) -> Result<(), AppError> {
+    match command {
+        AuthCommands::Login {
+            server_args,
+            storage,
+            web,
+            client_id,
+            port,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            if *web {
+                return web_login(&gitea_url, storage, client_id, *port).await;
+            }
+            let token = match token_option() {
+                Some(token) => token,
+                None => prompt_for_token(&gitea_url)?,
+            };
+            // Only a token that works is worth storing
+            let user = GiteaClient::with_token(&gitea_url, token.as_str())?
+                .get::<User>("user", &[])
+                .await?;
+            let location = match storage {
+                TokenStorage::Config => {
+                    let mut config = Config::load()?;
+                    config.server_mut(&gitea_url).token = Some(token);
+                    config.save()?;
+                    config_file()?.display().to_string()
+                }
+                TokenStorage::Store => {
+                    store_token(&gitea_url, token.as_str())?;
+                    store_file()?.display().to_string()
+                }
+                TokenStorage::Helper => {
+                    credential(
+                        "approve",
+                        &[
+                            ("url", gitea_url.as_str()),
+                            ("username", user.login.as_str()),
+                            ("password", token.as_str()),
+                        ],
+                    )?;
+                    String::from("the git credential helper")
+                }
+            };
+            crate::print_success!("Logged in to {} as {}", gitea_url, user.login);
+            crate::print_info!("Saved the token in {}", location);
+            Ok(())
+        }
+        AuthCommands::Logout {
+            server_args,
+            storage,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let mut locations = Vec::new();
+            if matches!(storage, None | Some(TokenStorage::Config)) {
+                let mut config = Config::load()?;
+                if config.take_token(&gitea_url).is_some() {
+                    config.save()?;
+                    locations.push(config_file()?.display().to_string());
+                }
+            }
+            if matches!(storage, None | Some(TokenStorage::Store)) && remove_token(&gitea_url)? {
+                locations.push(store_file()?.display().to_string());
+            }
+            // The credential helper doesn't say whether it had anything to forget.
+            // Without a storage, it's where the credentials came from if they
+            // came from nowhere else.
+            let helper = match storage {
+                Some(storage) => matches!(storage, TokenStorage::Helper),
+                None => locations.is_empty() && token_option().is_none() && !has_env_token(),
+            };
+            if helper {
+                credential("reject", &[("url", gitea_url.as_str())])?;
+                locations.push(String::from("the git credential helper"));
+            }
+            if locations.is_empty() {
+                crate::print_info!("There is no token for {} to forget", gitea_url);
+            } else {
+                crate::print_success!("Logged out of {}", gitea_url);
+                for location in locations.iter() {
+                    crate::print_info!("Removed the credentials from {}", location);
+                }
+            }
+            Ok(())
+        }
+        AuthCommands::Status { server_args, all } => {
+            let servers = if *all {
+                known_servers()?
+            } else {
+                vec![resolve_server_args(server_args)?]
+            };
+            if servers.is_empty() {
+                crate::print_info!("No servers in the config file or the token store");
+                return Ok(());
+            }
+            let mut failures = 0;
+            for gitea_url in servers.iter() {
+                match authenticated_user(gitea_url).await {
+                    Ok((user, source)) => crate::print_success!(
+                        "Logged in to {} as {} (credentials from {})",
+                        gitea_url,
+                        user.login,
+                        source
+                    ),
+                    Err(error) => {
+                        crate::print_error!("Not logged in to {}: {}", gitea_url, error);
+                        failures += 1;
+                    }
+                }
+            }
+            if failures == 0 {
+                Ok(())
+            } else {
+                Err(AppError::from(format!(
+                    "Not logged in to {} of {} servers",
+                    failures,
+                    servers.len()
+                )))
+            }
+        }
+    }
+}
+
+// A token from a web login is only useful where it can be refreshed
+async fn web_login(
+    gitea_url: &Url,
+    storage: &TokenStorage,
+    client_id: &Option<String>,
+    port: u16,
+) -> Result<(), AppError> {
+    if !matches!(storage, TokenStorage::Config) {
+        return Err(AppError::from(
+            "A web login can only be stored in the config file",
+        ));
+    }
+    let mut config = Config::load()?;
+    let server = config.server_mut(gitea_url);
+    let client_id = match client_id.as_ref().or(server.client_id.as_ref()) {
+        Some(client_id) => client_id.to_owned(),
+        None => {
+            return Err(AppError::from(
+                "The client ID of an OAuth2 application in Gitea is required",
+            ))
+        }
+    };
+    let token = oauth::web_login(gitea_url, client_id.as_str(), port).await?;
+    let user = GiteaClient::with_token(gitea_url, token.access_token.as_str())?
+        .get::<User>("user", &[])
+        .await?;
+    server.client_id = Some(client_id);
+    set_token(server, &token);
+    config.save()?;
+    crate::print_success!("Logged in to {} as {}", gitea_url, user.login);
+    crate::print_info!("Saved the token in {}", config_file()?.display());
+    Ok(())
+}
+
+// A token in the environment isn't stored anywhere to log out of
+fn has_env_token() -> bool {
+    std::env::var("GITEA_TOKEN").is_ok_and(|token| !token.is_empty())
+}
+
+fn prompt_for_token(gitea_url: &Url) -> Result<String, AppError> {
+    crate::print_info!(
+        "Create an access token at {}/user/settings/applications",
+        gitea_url.as_str().trim_end_matches('/')
+    );
+    let token = rpassword::prompt_password("➕ Access token: ")?;
+    let token = token.trim();
+    if token.is_empty() {
+        Err(AppError::Canceled)
+    } else {
+        Ok(token.to_string())
+    }
+}
+
+// The user the server's credentials belong to, and where they came from
+async fn authenticated_user(gitea_url: &Url) -> Result<(User, &'static str), AppError> {
+    let (gitea_client, source) = match token_and_source(gitea_url).await? {
+        Some((token, source)) => (GiteaClient::with_token(gitea_url, token.as_str())?, source),
+        None => (password_client(gitea_url)?, "the git credential helper"),
+    };
+    let user = gitea_client.get::<User>("user", &[]).await?;
+    Ok((user, source))
+}
+
+// The servers there are tokens for in the config file or the token store
+fn known_servers() -> Result<Vec<Url>, AppError> {
+    let config = Config::load()?;
+    let mut servers: Vec<Url> = Vec::new();
+    for server in config
+        .servers_with_tokens()
+        .cloned()
+        .chain(stored_servers()?)
+    {
+        let gitea_url = Url::parse(server.as_str())
+            .map_err(|_| AppError::from(format!("Invalid server URL '{}'", server)))?;
+        if !servers
+            .iter()
+            .any(|known| same_server(known.as_str(), &gitea_url))
+        {
+            servers.push(gitea_url);
+        }
+    }
+    Ok(servers)
+}
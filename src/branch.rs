@@ -0,0 +1,273 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{BranchCommands, BranchFilterArgs},
+    gitea_client::{authenticated_client, path_segment, GiteaClient},
+    models::Repository,
+    output::print_json,
+    prompt::confirm,
+    repo_context::RepoContext,
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Deserialize, Serialize)]
+struct Branch {
+    name: String,
+    commit: BranchCommit,
+    #[serde(default)]
+    protected: bool,
+}
+
+#[derive(Deserialize, Serialize)]
+struct BranchCommit {
+    id: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    timestamp: String,
+    author: Option<CommitUser>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CommitUser {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Serialize)]
+struct CreateBranchOption<'a> {
+    new_branch_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_branch_name: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct Compare {
+    total_commits: u64,
+}
+
+pub(crate) async fn branch(command: &BranchCommands) -> Result<(), AppError> {
+    match command {
+        BranchCommands::List {
+            repo_args,
+            filter_args,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let branches = get_branches(&gitea_client, &repo_context).await?;
+            let branches = if filtered(filter_args) {
+                select_branches(&gitea_client, &repo_context, branches, filter_args).await?
+            } else {
+                branches
+            };
+            if *json {
+                print_json(&branches)
+            } else if branches.is_empty() {
+                crate::print_info!("No matches");
+                Ok(())
+            } else {
+                let mut table =
+                    Table::new(&["Name", "Protected", "Date", "Commit", "Author", "Message"]);
+                for branch in branches.iter() {
+                    table.add_row(vec![
+                        branch.name.clone(),
+                        branch.protected.to_string(),
+                        branch.commit.timestamp.chars().take(10).collect(),
+                        branch.commit.id.chars().take(8).collect(),
+                        branch
+                            .commit
+                            .author
+                            .as_ref()
+                            .map(|author| author.name.clone())
+                            .unwrap_or_default(),
+                        branch
+                            .commit
+                            .message
+                            .lines()
+                            .next()
+                            .unwrap_or_default()
+                            .to_string(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        BranchCommands::Create {
+            repo_args,
+            name,
+            from,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = CreateBranchOption {
+                new_branch_name: name.as_str(),
+                old_branch_name: from.as_deref(),
+            };
+            let branch = gitea_client
+                .post::<_, Branch>(repo_context.api_path("/branches").as_str(), &options)
+                .await?;
+            crate::print_success!(
+                "Created branch {} in {} at {}",
+                branch.name,
+                repo_context.repo(),
+                branch.commit.id.chars().take(8).collect::<String>()
+            );
+            Ok(())
+        }
+        BranchCommands::Delete {
+            repo_args,
+            names,
+            filter_args,
+            dry_run,
+            yes,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let names = if filtered(filter_args) {
+                let branches = get_branches(&gitea_client, &repo_context)
+                    .await?
+                    .into_iter()
+                    .filter(|branch| names.is_empty() || names.contains(&branch.name))
+                    .collect();
+                select_branches(&gitea_client, &repo_context, branches, filter_args)
+                    .await?
+                    .into_iter()
+                    .map(|branch| branch.name)
+                    .collect()
+            } else {
+                names.to_owned()
+            };
+            if names.is_empty() {
+                crate::print_info!("No matches");
+                return Ok(());
+            }
+            crate::print_info!(
+                "{} {} branches from {}",
+                if *dry_run { "Would delete" } else { "Deleting" },
+                names.len(),
+                repo_context.repo()
+            );
+            for name in names.iter() {
+                println!("➖️{}", name);
+            }
+            if *dry_run {
+                return Ok(());
+            }
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            for name in names.iter() {
+                gitea_client
+                    .delete(
+                        repo_context
+                            .api_path(format!("/branches/{}", path_segment(name)).as_str())
+                            .as_str(),
+                    )
+                    .await?;
+                crate::print_success!("Deleted branch {}", name);
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn get_branches(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+) -> Result<Vec<Branch>, AppError> {
+    gitea_client
+        .get_all::<Branch>(repo_context.api_path("/branches").as_str(), &[])
+        .await
+}
+
+fn filtered(filter_args: &BranchFilterArgs) -> bool {
+    filter_args.merged || filter_args.older_than.is_some()
+}
+
+// The branches that match the filters. The default branch, the base branch
+// and protected branches are left out, so they can't be deleted by accident.
+async fn select_branches(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    branches: Vec<Branch>,
+    filter_args: &BranchFilterArgs,
+) -> Result<Vec<Branch>, AppError> {
+    let default_branch = gitea_client
+        .get::<Repository>(repo_context.api_path("").as_str(), &[])
+        .await?
+        .default_branch;
+    let base = filter_args.base.clone().unwrap_or(default_branch.clone());
+    let today = days_since_epoch();
+    let mut selected = Vec::new();
+    for branch in branches.into_iter() {
+        if branch.protected || branch.name == default_branch || branch.name == base {
+            continue;
+        }
+        if let Some(older_than) = filter_args.older_than {
+            match commit_day(&branch.commit.timestamp) {
+                Some(day) if today - day > older_than as i64 => {}
+                _ => continue,
+            }
+        }
+        if filter_args.merged && !is_merged(gitea_client, repo_context, &base, &branch).await? {
+            continue;
+        }
+        selected.push(branch);
+    }
+    Ok(selected)
+}
+
+// A branch is merged if it has no commits that aren't in the base branch
+async fn is_merged(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    base: &str,
+    branch: &Branch,
+) -> Result<bool, AppError> {
+    let compare = gitea_client
+        .get::<Compare>(
+            repo_context
+                .api_path(
+                    format!(
+                        "/compare/{}...{}",
+                        path_segment(base),
+                        path_segment(&branch.name)
+                    )
+                    .as_str(),
+                )
+                .as_str(),
+            &[],
+        )
+        .await?;
+    Ok(compare.total_commits == 0)
+}
+
+fn days_since_epoch() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| (duration.as_secs() / 86400) as i64)
+        .unwrap_or_default()
+}
+
+// The day of a commit timestamp (RFC 3339) as days since 1970-01-01.
+// The time of day and time zone don't matter for an age in days.
+fn commit_day(timestamp: &str) -> Option<i64> {
+    let date = timestamp.get(0..10)?;
+    let mut parts = date.split('-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    // Howard Hinnant's days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146097 + day_of_era - 719468)
+}
@@ -3,33 +3,318 @@
  * Created 2024-03-13
  */
 
-use crate::app_error::AppError;
-use git_lib::GitLib;
-use std::ffi::OsStr;
-use std::path::PathBuf;
-
-pub(crate) fn browse(path: &Option<PathBuf>, remote_name: &String) -> Result<(), AppError> {
-    let path = match path {
-        Some(path) => path.to_owned(),
-        None => std::env::current_dir().unwrap_or_default(),
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{BrowseTarget, RepoArgs},
+    git::{current_branch, git, resolve_commit},
+    gitea_client::{authenticated_client, resolve_gitea_url},
+    models::Repository,
+    prompt::choose,
+    remote_url::RemoteUrl,
+    repo_context::{local_path, remote_url, resolve_server},
+    repo_name::RepoName,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+pub(crate) async fn browse(
+    repo_args: &RepoArgs,
+    verify: &bool,
+    print: &bool,
+    copy: &bool,
+    target: &BrowseTarget,
+) -> Result<(), AppError> {
+    let local_remote_url = remote_url(&repo_args.path, &repo_args.remote_name);
+    let web_url = if let Some(repo) = &repo_args.repo {
+        let repository = get_repository(&resolve_server(repo_args)?, repo).await?;
+        target_url(
+            Url::parse(repository.html_url.as_str())?,
+            None,
+            None,
+            target,
+        )?
+    } else if let Some(filter) = search_filter(&local_remote_url, &target.location) {
+        let repository = search_repository(&resolve_server(repo_args)?, filter).await?;
+        target_url(
+            Url::parse(repository.html_url.as_str())?,
+            None,
+            None,
+            target,
+        )?
+    } else {
+        let remote_url = local_remote_url?;
+        let web_url = if *verify {
+            api_web_url(&remote_url).await?
+        } else {
+            remote_url.web_url().ok_or_else(|| {
+                AppError::from(format!(
+                    "Could not get a web URL from remote '{}'",
+                    repo_args.remote_name
+                ))
+            })?
+        };
+        target_url(
+            web_url,
+            Some(&local_path(&repo_args.path)),
+            target.location.as_deref(),
+            target,
+        )?
     };
-    match GitLib::remote_url(remote_name.as_str(), Option::from(&path)) {
-        Ok(remote_url) => {
-            let ru = <String as AsRef<OsStr>>::as_ref(&remote_url);
-            match open::that_detached(ru) {
-                Ok(()) => {
-                    crate::print_success!("Opened '{}'", remote_url);
-                    Ok(())
-                }
-                Err(error) => Err(AppError::from(format!(
-                    "Could not open '{}': {}",
-                    remote_name, error
-                ))),
-            }
+    if *print {
+        println!("{}", web_url);
+    }
+    if *copy {
+        copy_to_clipboard(web_url.as_str())?;
+        crate::print_success!("Copied '{}' to the clipboard", web_url);
+    }
+    if *print || *copy {
+        return Ok(());
+    }
+    if !has_display() {
+        crate::print_info!("No display to open a browser on");
+        println!("{}", web_url);
+        return Ok(());
+    }
+    match open::that_detached(web_url.as_str()) {
+        Ok(()) => crate::print_success!("Opened '{}'", web_url),
+        Err(error) => {
+            crate::print_info!("Could not open a browser: {}", error);
+            println!("{}", web_url);
         }
-        Err(error) => Err(AppError::from(format!(
-            "Could not get remote URL for '{}': {}",
-            remote_name, error
+    }
+    Ok(())
+}
+
+// Windows and macOS always have a display. Elsewhere, a browser needs X11 or Wayland,
+// unless BROWSER names one that doesn't, e.g. a text mode browser.
+fn has_display() -> bool {
+    cfg!(any(target_os = "windows", target_os = "macos"))
+        || std::env::var_os("BROWSER").is_some()
+        || std::env::var_os("DISPLAY").is_some()
+        || std::env::var_os("WAYLAND_DISPLAY").is_some()
+}
+
+// The OSC 52 escape sequence asks the terminal to set the clipboard, which also
+// works in an SSH session. Not every terminal supports it. The sequence goes to the
+// terminal itself rather than stdout, so it doesn't end up in redirected output.
+fn copy_to_clipboard(text: &str) -> Result<(), AppError> {
+    let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+    // tmux and screen only pass a sequence on to the outer terminal wrapped in
+    // a DCS sequence; tmux also needs allow-passthrough turned on
+    let sequence = if std::env::var_os("TMUX").is_some() {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else if std::env::var("TERM").is_ok_and(|term| term.starts_with("screen")) {
+        format!("\x1bP{}\x1b\\", sequence)
+    } else {
+        sequence
+    };
+    match std::fs::OpenOptions::new().write(true).open("/dev/tty") {
+        Ok(mut tty) => {
+            tty.write_all(sequence.as_bytes())?;
+            tty.flush()?;
+        }
+        Err(_) => {
+            let mut stderr = std::io::stderr();
+            stderr.write_all(sequence.as_bytes())?;
+            stderr.flush()?;
+        }
+    }
+    Ok(())
+}
+
+// The web server may not be where the remote URL suggests, e.g. for an SSH remote
+// on a different host, but Gitea knows the repository's web URL.
+async fn api_web_url(remote_url: &RemoteUrl) -> Result<Url, AppError> {
+    let repo = remote_url
+        .repo_name()
+        .ok_or_else(|| AppError::from("Could not get the repository name from the remote"))?;
+    let gitea_url = remote_url
+        .gitea_url()
+        .or_else(|| resolve_gitea_url(&None).ok())
+        .or_else(|| remote_url.web_server_url())
+        .ok_or_else(|| AppError::from("Missing or invalid Gitea URL"))?;
+    let repository = get_repository(&gitea_url, &repo).await?;
+    Ok(Url::parse(repository.html_url.as_str())?)
+}
+
+async fn get_repository(gitea_url: &Url, repo: &RepoName) -> Result<Repository, AppError> {
+    authenticated_client(gitea_url)
+        .await?
+        .get::<Repository>(
+            format!("repos/{}/{}", repo.owner(), repo.name()).as_str(),
+            &[],
+        )
+        .await
+}
+
+// Without a local checkout, the location is a filter for finding the repository
+// by name. In a checkout, it's a file or directory, which has to exist.
+fn search_filter<'a>(
+    local_remote_url: &Result<RemoteUrl, AppError>,
+    location: &'a Option<String>,
+) -> Option<&'a str> {
+    match local_remote_url {
+        Ok(_) => None,
+        Err(_) => location.as_deref(),
+    }
+}
+
+// Asks which repository was meant if more than one matches
+async fn search_repository(gitea_url: &Url, filter: &str) -> Result<Repository, AppError> {
+    let gitea_client = authenticated_client(gitea_url).await?;
+    let mut repositories = gitea_client
+        .search_repos::<Repository>(&[("q", filter.to_owned())])
+        .await?;
+    match repositories.len() {
+        0 => Err(AppError::from(format!(
+            "No repositories match '{}'",
+            filter
         ))),
+        1 => Ok(repositories.remove(0)),
+        _ => {
+            let choices = repositories
+                .iter()
+                .map(|repository| repository.full_name.clone())
+                .collect::<Vec<String>>();
+            let index = choose("Repository number", &choices)?;
+            Ok(repositories.swap_remove(index))
+        }
+    }
+}
+
+// The repository's web URL, extended to the page of the target. A location can only
+// be shown from a local checkout, which is also where commits are looked up.
+fn target_url(
+    web_url: Url,
+    repo_path: Option<&Path>,
+    location: Option<&str>,
+    target: &BrowseTarget,
+) -> Result<Url, AppError> {
+    let mut segments = Vec::new();
+    let mut fragment = None;
+    let page = page(target);
+    if let (Some(_), Some(location)) = (&page, location) {
+        return Err(AppError::from(format!(
+            "'{}' can't be combined with the page to open",
+            location
+        )));
+    }
+    if let Some(page) = page {
+        segments.extend(page.split('/').map(str::to_string));
+    } else if let (Some(location), Some(repo_path)) = (location, repo_path) {
+        let (file, lines) = split_location(location);
+        let (kind, reference) = match (&target.branch, &target.commit) {
+            (Some(branch), _) => ("branch", branch.to_owned()),
+            (None, Some(commit)) => ("commit", resolve_commit(repo_path, commit)?),
+            // A detached HEAD has no branch
+            (None, None) => match current_branch(repo_path) {
+                Ok(branch) => ("branch", branch),
+                Err(_) => ("commit", resolve_commit(repo_path, "HEAD")?),
+            },
+        };
+        segments.push(String::from("src"));
+        segments.push(kind.to_string());
+        segments.extend(reference.split('/').map(str::to_string));
+        segments.extend(repo_relative_path(repo_path, file)?);
+        fragment = lines;
+    } else if let Some(branch) = &target.branch {
+        segments.push(String::from("src"));
+        segments.push(String::from("branch"));
+        segments.extend(branch.split('/').map(str::to_string));
+    } else if let Some(commit) = &target.commit {
+        segments.push(String::from("commit"));
+        segments.push(match repo_path {
+            Some(repo_path) => resolve_commit(repo_path, commit)?,
+            None => commit.to_owned(),
+        });
+    }
+    let mut url = web_url;
+    // HTTP(S) URLs always have a path
+    if let Ok(mut path) = url.path_segments_mut() {
+        path.pop_if_empty().extend(segments.iter());
+    }
+    url.set_fragment(fragment.as_deref());
+    Ok(url)
+}
+
+fn page(target: &BrowseTarget) -> Option<String> {
+    let page = if let Some(issue) = target.issue {
+        // Gitea redirects to the pull request if the number is one
+        format!("issues/{}", issue)
+    } else if target.issues {
+        String::from("issues")
+    } else if target.pulls {
+        String::from("pulls")
+    } else if target.releases {
+        String::from("releases")
+    } else if target.wiki {
+        String::from("wiki")
+    } else if target.settings {
+        String::from("settings")
+    } else if target.actions {
+        String::from("actions")
+    } else {
+        return None;
+    };
+    Some(page)
+}
+
+// Splits path:line or path:line-line into the path and Gitea's line anchor, L10 or L10-L20
+fn split_location(location: &str) -> (&str, Option<String>) {
+    let is_line = |line: &str| !line.is_empty() && line.chars().all(|c| c.is_ascii_digit());
+    if let Some((file, lines)) = location.rsplit_once(':') {
+        match lines.split_once('-') {
+            Some((first, last)) if is_line(first) && is_line(last) => {
+                return (file, Some(format!("L{}-L{}", first, last)));
+            }
+            None if is_line(lines) => return (file, Some(format!("L{}", lines))),
+            _ => {}
+        }
+    }
+    (location, None)
+}
+
+// The path segments of a local file or directory, relative to the top of the repository.
+// A relative path is relative to the repository path, which defaults to the current one.
+fn repo_relative_path(repo_path: &Path, file: &str) -> Result<Vec<String>, AppError> {
+    let top = PathBuf::from(git(repo_path, &["rev-parse", "--show-toplevel"])?).canonicalize()?;
+    let file_path = repo_path
+        .join(file)
+        .canonicalize()
+        .map_err(|_| AppError::NotFound(format!("File not found: '{}'", file)))?;
+    let relative = file_path
+        .strip_prefix(&top)
+        .map_err(|_| AppError::from(format!("'{}' is not in the repository", file)))?;
+    Ok(relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_location;
+
+    #[test]
+    fn locations_with_lines() {
+        assert_eq!(
+            split_location("src/main.rs:12"),
+            ("src/main.rs", Some(String::from("L12")))
+        );
+        assert_eq!(
+            split_location("src/main.rs:3-7"),
+            ("src/main.rs", Some(String::from("L3-L7")))
+        );
+    }
+
+    #[test]
+    fn locations_without_lines() {
+        assert_eq!(split_location("src/main.rs"), ("src/main.rs", None));
+        assert_eq!(split_location("docs/a:b.md"), ("docs/a:b.md", None));
+        assert_eq!(split_location("src/main.rs:"), ("src/main.rs:", None));
+        assert_eq!(split_location("src/main.rs:3-"), ("src/main.rs:3-", None));
+        assert_eq!(split_location("src/main.rs:x"), ("src/main.rs:x", None));
     }
 }
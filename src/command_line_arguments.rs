@@ -5,16 +5,53 @@
 
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use url::Url;
 
+use crate::repo_name::RepoName;
+
 #[derive(Parser)]
 #[command(version, about, long_about = None, author, arg_required_else_help = true)]
 pub struct CommandLineArguments {
+    /// Access token for the Gitea server [default: GITEA_TOKEN, the config file or the token store]
+    #[arg(long, global = true)]
+    pub(crate) token: Option<String>,
+
+    #[command(flatten, next_help_heading = "Connection")]
+    pub(crate) connection_args: ConnectionArgs,
+
     #[command(subcommand)]
     pub(crate) command: Option<Commands>,
 }
 
+// TLS and proxy settings for every command. They override the ones in the config file.
+#[derive(Args, Clone)]
+pub struct ConnectionArgs {
+    /// PEM file of CA certificates to trust, e.g. an internal CA's
+    #[arg(long, global = true)]
+    pub(crate) ca_cert: Option<PathBuf>,
+
+    /// PEM file of a client certificate to authenticate with
+    #[arg(long, global = true)]
+    pub(crate) client_cert: Option<PathBuf>,
+
+    /// PEM file of the client certificate's private key, in PKCS #8 format
+    #[arg(long, global = true)]
+    pub(crate) client_key: Option<PathBuf>,
+
+    /// Don't verify the server's certificate. This is insecure!
+    #[arg(long, global = true)]
+    pub(crate) insecure: bool,
+
+    /// Proxy URL, e.g. http://proxy:3128 or socks5://proxy:1080 [default: HTTPS_PROXY, HTTP_PROXY or ALL_PROXY]
+    #[arg(long, global = true)]
+    pub(crate) proxy: Option<String>,
+
+    /// Comma separated hosts or domains to connect to without the proxy [default: NO_PROXY]
+    #[arg(long, global = true)]
+    pub(crate) no_proxy: Option<String>,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// List repositories
@@ -26,16 +63,38 @@ pub enum Commands {
         /// Only list remotes whose name contains this value
         #[arg()]
         filter: Option<String>,
+
+        /// Only list repositories with this topic (may be repeated)
+        #[arg(short, long("topic"))]
+        topics: Vec<String>,
+
+        /// Include a column with each repository's topics
+        #[arg(short('T'), long)]
+        show_topics: bool,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
     },
     /// Open remote repository URL in default browser
     Browse {
-        /// Repository path [default: current path]
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Get the web URL from the Gitea API instead of deriving it from the remote URL
         #[arg(long)]
-        path: Option<PathBuf>,
+        verify: bool,
 
-        /// Remote name
-        #[arg(short, long, default_value = "origin")]
-        remote_name: String,
+        /// Print the URL instead of opening it
+        #[arg(short, long)]
+        print: bool,
+
+        /// Copy the URL to the clipboard (via the terminal) instead of opening it
+        #[arg(long)]
+        copy: bool,
+
+        #[command(flatten)]
+        target: BrowseTarget,
     },
     /// Create a remote repository and track it locally
     Create {
@@ -78,5 +137,1894 @@ pub enum Commands {
         /// The repository should *not* be a template (default)
         #[arg(long, value_name = "not-template", conflicts_with = "template")]
         not_template: bool,
+
+        /// Branch protection rule file to apply to the new repository, as written by protect export
+        #[arg(long, value_name = "RULE_FILE")]
+        protection: Option<PathBuf>,
+    },
+    /// Manage pull mirrors of external repositories
+    Mirror {
+        #[command(subcommand)]
+        command: MirrorCommands,
+    },
+    /// Manage push mirrors of a repository
+    PushMirror {
+        #[command(subcommand)]
+        command: PushMirrorCommands,
+    },
+    /// Manage the issues of a repository
+    Issue {
+        #[command(subcommand)]
+        command: IssueCommands,
+    },
+    /// Manage the pull requests of a repository
+    Pr {
+        #[command(subcommand)]
+        command: PrCommands,
+    },
+    /// Manage the releases of a repository
+    Release {
+        #[command(subcommand)]
+        command: ReleaseCommands,
+    },
+    /// Manage the labels of a repository or organization
+    Label {
+        #[command(subcommand)]
+        command: LabelCommands,
+    },
+    /// Manage the milestones of a repository
+    Milestone {
+        #[command(subcommand)]
+        command: MilestoneCommands,
+    },
+    /// Manage the webhooks of a repository or organization
+    Hook {
+        #[command(subcommand)]
+        command: HookCommands,
+    },
+    /// Manage the deploy keys of a repository
+    DeployKey {
+        #[command(subcommand)]
+        command: DeployKeyCommands,
+    },
+    /// Manage who has access to a repository
+    Access {
+        #[command(subcommand)]
+        command: AccessCommands,
+    },
+    /// Manage organizations
+    Org {
+        #[command(subcommand)]
+        command: OrgCommands,
+    },
+    /// Manage the teams of an organization
+    Team {
+        #[command(subcommand)]
+        command: TeamCommands,
+    },
+    /// Manage the branch protection rules of a repository
+    Protect {
+        #[command(subcommand)]
+        command: ProtectCommands,
+    },
+    /// Manage the branches of a remote repository
+    Branch {
+        #[command(subcommand)]
+        command: BranchCommands,
+    },
+    /// Manage the tags of a remote repository
+    Tag {
+        #[command(subcommand)]
+        command: TagCommands,
+    },
+    /// Manage the topics of a repository
+    Topics {
+        #[command(subcommand)]
+        command: TopicsCommands,
+    },
+    /// Report or show the status of a commit, e.g. from CI
+    Status {
+        #[command(subcommand)]
+        command: StatusCommands,
+    },
+    /// Manage access tokens
+    Token {
+        #[command(subcommand)]
+        command: TokenCommands,
+    },
+    /// Log in to and out of Gitea servers
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
+    },
+    /// Send a request to any Gitea API endpoint, and print the response
+    Api {
+        #[command(flatten)]
+        api_args: ApiArgs,
+    },
+}
+
+// What browse opens. A file or directory is shown on the current branch unless
+// a branch or commit is specified. The other pages can't be combined.
+#[derive(Args)]
+pub struct BrowseTarget {
+    /// File or directory, optionally with a line or range of lines, e.g. src/main.rs:10-20.
+    /// Without a local checkout, a filter to search for the repository by name.
+    #[arg(value_name = "PATH[:LINE[-LINE]]|FILTER", conflicts_with = "repo")]
+    pub(crate) location: Option<String>,
+
+    /// Branch to show
+    #[arg(short, long, conflicts_with_all = ["commit", "page"])]
+    pub(crate) branch: Option<String>,
+
+    /// Commit to show (SHA, or a local branch or tag)
+    #[arg(short, long, conflicts_with = "page")]
+    pub(crate) commit: Option<String>,
+
+    /// Open the issues
+    #[arg(long, group = "page")]
+    pub(crate) issues: bool,
+
+    /// Open an issue or pull request
+    #[arg(long, value_name = "NUMBER", group = "page")]
+    pub(crate) issue: Option<u64>,
+
+    /// Open the pull requests
+    #[arg(long, group = "page")]
+    pub(crate) pulls: bool,
+
+    /// Open the releases
+    #[arg(long, group = "page")]
+    pub(crate) releases: bool,
+
+    /// Open the wiki
+    #[arg(long, group = "page")]
+    pub(crate) wiki: bool,
+
+    /// Open the settings
+    #[arg(long, group = "page")]
+    pub(crate) settings: bool,
+
+    /// Open the actions
+    #[arg(long, group = "page")]
+    pub(crate) actions: bool,
+}
+
+// Identifies the repository a command acts on
+#[derive(Args)]
+pub struct RepoArgs {
+    /// Gitea URL [default: from the remote URL, or GITEA_URL]
+    #[arg(short('u'), long)]
+    pub(crate) gitea_url: Option<Url>,
+
+    /// Repository (owner/name) [default: from the remote URL]
+    #[arg(long)]
+    pub(crate) repo: Option<RepoName>,
+
+    /// Repository path [default: current path]
+    #[arg(long)]
+    pub(crate) path: Option<PathBuf>,
+
+    /// Remote name
+    #[arg(short, long, default_value = "origin")]
+    pub(crate) remote_name: String,
+}
+
+// Identifies the repository or organization a command acts on
+#[derive(Args)]
+pub struct ScopeArgs {
+    #[command(flatten)]
+    pub(crate) repo_args: RepoArgs,
+
+    /// Act on this organization instead of a repository
+    #[arg(long, conflicts_with = "repo")]
+    pub(crate) org: Option<String>,
+}
+
+// Identifies the Gitea server, for commands that don't act on a particular repository
+#[derive(Args)]
+pub struct ServerArgs {
+    /// Gitea URL [default: from the remote URL, or GITEA_URL]
+    #[arg(short('u'), long)]
+    pub(crate) gitea_url: Option<Url>,
+
+    /// Repository path [default: current path]
+    #[arg(long)]
+    pub(crate) path: Option<PathBuf>,
+
+    /// Remote name
+    #[arg(short, long, default_value = "origin")]
+    pub(crate) remote_name: String,
+}
+
+#[derive(Subcommand)]
+pub enum MirrorCommands {
+    /// Create a pull mirror of an external repository
+    Create {
+        /// Gitea URL
+        #[arg(short('u'), long)]
+        gitea_url: Option<Url>,
+
+        /// URL of the repository to mirror
+        #[arg()]
+        clone_url: Url,
+
+        /// User or organization that will own the mirror [default: authenticated user]
+        #[arg(short, long)]
+        owner: Option<String>,
+
+        /// Mirror name [default: last segment of the clone URL]
+        #[arg(short, long)]
+        gitea_name: Option<String>,
+
+        /// Mirror description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Sync interval, e.g. 8h0m0s [default: server default]
+        #[arg(short, long)]
+        interval: Option<String>,
+
+        /// The mirror should be private
+        #[arg(long)]
+        private: bool,
+
+        /// Username for the repository being mirrored
+        #[arg(long)]
+        auth_username: Option<String>,
+
+        /// Password or token for the repository being mirrored
+        #[arg(long)]
+        auth_password: Option<String>,
+    },
+    /// Sync a mirror with its original repository now
+    Sync {
+        /// Gitea URL
+        #[arg(short('u'), long)]
+        gitea_url: Option<Url>,
+
+        /// Mirror repository (owner/name)
+        #[arg()]
+        repo: RepoName,
+    },
+    /// List mirrors with their sync interval and last sync time
+    List {
+        /// Gitea URL
+        #[arg(short('u'), long)]
+        gitea_url: Option<Url>,
+
+        /// Only list mirrors whose name contains this value
+        #[arg()]
+        filter: Option<String>,
+    },
+    /// Change a mirror's sync interval
+    Edit {
+        /// Gitea URL
+        #[arg(short('u'), long)]
+        gitea_url: Option<Url>,
+
+        /// Mirror repository (owner/name)
+        #[arg()]
+        repo: RepoName,
+
+        /// Sync interval, e.g. 8h0m0s, or 0 to disable periodic sync
+        #[arg(short, long)]
+        interval: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PushMirrorCommands {
+    /// Add a push mirror
+    Add {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// URL of the repository to push to
+        #[arg()]
+        remote_address: Url,
+
+        /// Username for the repository being pushed to
+        #[arg(long)]
+        remote_username: Option<String>,
+
+        /// Password or token for the repository being pushed to
+        #[arg(long)]
+        remote_password: Option<String>,
+
+        /// Push interval, e.g. 8h0m0s, or 0 to disable periodic push
+        #[arg(short, long, default_value = "8h0m0s")]
+        interval: String,
+
+        /// Also push whenever commits are pushed to the repository
+        #[arg(long)]
+        sync_on_commit: bool,
+    },
+    /// List push mirrors with the status of their last update
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
     },
+    /// Remove a push mirror
+    Remove {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Name of the push mirror, as shown by the list command
+        #[arg()]
+        name: String,
+    },
+    /// Push to all push mirrors now
+    Sync {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum IssueState {
+    Open,
+    Closed,
+    All,
+}
+
+#[derive(Subcommand)]
+pub enum IssueCommands {
+    /// List issues
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Only list issues in this state
+        #[arg(short, long, value_enum, default_value = "open")]
+        state: IssueState,
+
+        /// Only list issues with this label (may be repeated)
+        #[arg(short('l'), long("label"))]
+        labels: Vec<String>,
+
+        /// Only list issues assigned to this user
+        #[arg(short, long)]
+        assignee: Option<String>,
+
+        /// Only list issues in this milestone
+        #[arg(short, long)]
+        milestone: Option<String>,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show an issue
+    View {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Issue number
+        #[arg()]
+        number: u64,
+
+        /// Also show the issue's comments
+        #[arg(short, long)]
+        comments: bool,
+
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create an issue
+    Create {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Issue title [default: prompt]
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Issue body [default: edit in $EDITOR]
+        #[arg(short, long)]
+        body: Option<String>,
+
+        /// Assign the issue to this user (may be repeated)
+        #[arg(short('a'), long("assignee"))]
+        assignees: Vec<String>,
+
+        /// Add this label to the issue (may be repeated)
+        #[arg(short('l'), long("label"))]
+        labels: Vec<String>,
+
+        /// Add the issue to this milestone
+        #[arg(short, long)]
+        milestone: Option<String>,
+    },
+    /// Close an issue
+    Close {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Issue number
+        #[arg()]
+        number: u64,
+
+        /// Comment to add before closing the issue
+        #[arg(short, long)]
+        comment: Option<String>,
+    },
+    /// Reopen a closed issue
+    Reopen {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Issue number
+        #[arg()]
+        number: u64,
+    },
+    /// Comment on an issue
+    Comment {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Issue number
+        #[arg()]
+        number: u64,
+
+        /// Comment text [default: edit in $EDITOR]
+        #[arg(short, long)]
+        body: Option<String>,
+    },
+    /// Change an issue's title or body
+    Edit {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Issue number
+        #[arg()]
+        number: u64,
+
+        /// New title
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// New body
+        #[arg(short, long, conflicts_with = "editor")]
+        body: Option<String>,
+
+        /// Edit the current body in $EDITOR
+        #[arg(short, long)]
+        editor: bool,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum MergeStyle {
+    Merge,
+    Rebase,
+    RebaseMerge,
+    Squash,
+    FastForward,
+}
+
+#[derive(Subcommand)]
+pub enum PrCommands {
+    /// List pull requests
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Only list pull requests in this state
+        #[arg(short, long, value_enum, default_value = "open")]
+        state: IssueState,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show a pull request
+    View {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Pull request number
+        #[arg()]
+        number: u64,
+
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Push the current branch and open a pull request for it
+    Create {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Pull request title [default: prompt]
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Pull request description [default: edit in $EDITOR]
+        #[arg(short, long)]
+        body: Option<String>,
+
+        /// Branch to merge into [default: the repository's default branch]
+        #[arg(short('B'), long)]
+        base: Option<String>,
+
+        /// Branch to merge from [default: current branch]
+        #[arg(short('H'), long)]
+        head: Option<String>,
+
+        /// Don't push the head branch before creating the pull request
+        #[arg(long)]
+        no_push: bool,
+
+        /// Assign the pull request to this user (may be repeated)
+        #[arg(short('a'), long("assignee"))]
+        assignees: Vec<String>,
+
+        /// Add this label to the pull request (may be repeated)
+        #[arg(short('l'), long("label"))]
+        labels: Vec<String>,
+
+        /// Add the pull request to this milestone
+        #[arg(short, long)]
+        milestone: Option<String>,
+    },
+    /// Fetch a pull request's branch and check it out locally
+    Checkout {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Pull request number
+        #[arg()]
+        number: u64,
+
+        /// Local branch name. An existing branch is only fast-forwarded [default: pr-NUMBER]
+        #[arg(short, long)]
+        branch: Option<String>,
+    },
+    /// Merge a pull request
+    Merge {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Pull request number
+        #[arg()]
+        number: u64,
+
+        /// How to merge the pull request
+        #[arg(short, long, value_enum, default_value = "merge")]
+        style: MergeStyle,
+
+        /// Merge commit title
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Merge commit message
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Delete the head branch after merging
+        #[arg(short, long)]
+        delete_branch: bool,
+    },
+    /// Close a pull request without merging it
+    Close {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Pull request number
+        #[arg()]
+        number: u64,
+    },
+    /// Show the changes in a pull request
+    Diff {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Pull request number
+        #[arg()]
+        number: u64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReleaseCommands {
+    /// List releases
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show a release
+    View {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Release tag
+        #[arg()]
+        tag: String,
+
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a release, and the tag if it doesn't exist
+    Create {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Release tag
+        #[arg()]
+        tag: String,
+
+        /// Branch or commit to tag [default: the repository's default branch]
+        #[arg(long)]
+        target: Option<String>,
+
+        /// Release title [default: the tag]
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Release notes
+        #[arg(short, long, conflicts_with_all = ["notes_file", "generate_notes"])]
+        notes: Option<String>,
+
+        /// Read the release notes from this file
+        #[arg(short('F'), long, conflicts_with = "generate_notes")]
+        notes_file: Option<PathBuf>,
+
+        /// Generate release notes from the local commits since the previous tag
+        #[arg(short, long)]
+        generate_notes: bool,
+
+        /// The release should be a draft
+        #[arg(long)]
+        draft: bool,
+
+        /// The release should be a prerelease
+        #[arg(long)]
+        prerelease: bool,
+
+        /// Files to upload as release assets
+        #[arg()]
+        files: Vec<PathBuf>,
+
+        /// Also upload a SHA256SUMS file with the checksums of the assets
+        #[arg(long)]
+        checksums: bool,
+    },
+    /// Change a release
+    Edit {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Release tag
+        #[arg()]
+        tag: String,
+
+        /// Release title
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Release notes
+        #[arg(short, long, conflicts_with = "notes_file")]
+        notes: Option<String>,
+
+        /// Read the release notes from this file
+        #[arg(short('F'), long)]
+        notes_file: Option<PathBuf>,
+
+        /// The release should be a draft
+        #[arg(long, conflicts_with = "not_draft")]
+        draft: bool,
+
+        /// The release should *not* be a draft, i.e. publish it
+        #[arg(long, conflicts_with = "draft")]
+        not_draft: bool,
+
+        /// The release should be a prerelease
+        #[arg(long, conflicts_with = "not_prerelease")]
+        prerelease: bool,
+
+        /// The release should *not* be a prerelease
+        #[arg(long, conflicts_with = "prerelease")]
+        not_prerelease: bool,
+    },
+    /// Delete a release
+    Delete {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Release tag
+        #[arg()]
+        tag: String,
+
+        /// Also delete the tag
+        #[arg(long)]
+        delete_tag: bool,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Upload files as release assets
+    Upload {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Release tag
+        #[arg()]
+        tag: String,
+
+        /// Files to upload
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Also upload a SHA256SUMS file with the checksums of the files
+        #[arg(long)]
+        checksums: bool,
+    },
+    /// Download release assets, verifying them against the release's SHA256SUMS if it has one
+    Download {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Release tag
+        #[arg()]
+        tag: String,
+
+        /// Only download the asset with this name (may be repeated)
+        #[arg(short('n'), long("name"))]
+        names: Vec<String>,
+
+        /// Directory to download to [default: current path]
+        #[arg(short, long)]
+        directory: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum FileFormat {
+    Yaml,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum LabelCommands {
+    /// List labels
+    List {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a label
+    Create {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Label name
+        #[arg()]
+        name: String,
+
+        /// Label color, e.g. #ee0701
+        #[arg(short, long)]
+        color: String,
+
+        /// Label description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    /// Change a label
+    Edit {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Label name
+        #[arg()]
+        name: String,
+
+        /// New label name
+        #[arg(short('n'), long)]
+        new_name: Option<String>,
+
+        /// New label color, e.g. #ee0701
+        #[arg(short, long)]
+        color: Option<String>,
+
+        /// New label description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    /// Delete a label
+    Delete {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Label name
+        #[arg()]
+        name: String,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Write the labels to a file
+    Export {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// File to write [default: standard output]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// File format [default: from the file extension, or yaml]
+        #[arg(short, long, value_enum)]
+        format: Option<FileFormat>,
+    },
+    /// Create the labels in a file that don't already exist
+    Import {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// YAML or JSON label file, as written by export
+        #[arg()]
+        file: PathBuf,
+    },
+    /// Make the labels of one or more repositories match a file
+    Sync {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// YAML or JSON label file, as written by export
+        #[arg()]
+        file: PathBuf,
+
+        /// Repository to sync (owner/name, may be repeated) [default: the current repository]
+        #[arg(short, long("target"))]
+        targets: Vec<RepoName>,
+
+        /// Don't delete labels that aren't in the file
+        #[arg(long)]
+        keep_extra: bool,
+
+        /// Only report what would change
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MilestoneCommands {
+    /// List milestones with their progress
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Only list milestones in this state
+        #[arg(short, long, value_enum, default_value = "open")]
+        state: IssueState,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a milestone
+    Create {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Milestone title
+        #[arg()]
+        title: String,
+
+        /// Milestone description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Due date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
+    },
+    /// Change a milestone
+    Edit {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Milestone title
+        #[arg()]
+        title: String,
+
+        /// New milestone title
+        #[arg(short('t'), long)]
+        new_title: Option<String>,
+
+        /// New milestone description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// New due date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
+    },
+    /// Close a milestone
+    Close {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Milestone title
+        #[arg()]
+        title: String,
+    },
+    /// Reopen a closed milestone
+    Reopen {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Milestone title
+        #[arg()]
+        title: String,
+    },
+    /// Delete a milestone
+    Delete {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Milestone title
+        #[arg()]
+        title: String,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum HookType {
+    Gitea,
+    Gogs,
+    Slack,
+    Discord,
+    Dingtalk,
+    Telegram,
+    Msteams,
+    Feishu,
+    Matrix,
+    Wechatwork,
+    Packagist,
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum ContentType {
+    Json,
+    Form,
+}
+
+#[derive(Subcommand)]
+pub enum HookCommands {
+    /// List webhooks
+    List {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a webhook
+    Create {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// URL to deliver events to
+        #[arg()]
+        url: Url,
+
+        /// Webhook type
+        #[arg(short('T'), long("type"), value_enum, default_value = "gitea")]
+        hook_type: HookType,
+
+        /// Event to deliver, e.g. push, pull_request, issues (may be repeated) [default: push]
+        #[arg(short, long("event"))]
+        events: Vec<String>,
+
+        /// Content type of deliveries
+        #[arg(short, long, value_enum, default_value = "json")]
+        content_type: ContentType,
+
+        /// Secret used to sign deliveries
+        #[arg(short, long)]
+        secret: Option<String>,
+
+        /// Only deliver push events for branches matching this glob pattern
+        #[arg(short, long)]
+        branch_filter: Option<String>,
+
+        /// Channel, for Slack and similar webhook types
+        #[arg(long)]
+        channel: Option<String>,
+
+        /// Create the webhook inactive
+        #[arg(long)]
+        inactive: bool,
+    },
+    /// Change a webhook
+    Edit {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Webhook ID
+        #[arg()]
+        id: u64,
+
+        /// URL to deliver events to
+        #[arg(long)]
+        url: Option<Url>,
+
+        /// Event to deliver (may be repeated, replaces the current events)
+        #[arg(short, long("event"))]
+        events: Vec<String>,
+
+        /// Content type of deliveries
+        #[arg(short, long, value_enum)]
+        content_type: Option<ContentType>,
+
+        /// Secret used to sign deliveries
+        #[arg(short, long)]
+        secret: Option<String>,
+
+        /// Only deliver push events for branches matching this glob pattern
+        #[arg(short, long)]
+        branch_filter: Option<String>,
+
+        /// Channel, for Slack and similar webhook types
+        #[arg(long)]
+        channel: Option<String>,
+
+        /// Activate the webhook
+        #[arg(long, conflicts_with = "inactive")]
+        active: bool,
+
+        /// Deactivate the webhook
+        #[arg(long, conflicts_with = "active")]
+        inactive: bool,
+    },
+    /// Delete a webhook
+    Delete {
+        #[command(flatten)]
+        scope_args: ScopeArgs,
+
+        /// Webhook ID
+        #[arg()]
+        id: u64,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Queue a test delivery to a repository webhook
+    ///
+    /// Gitea's API doesn't report how the delivery went; its response is shown
+    /// in the webhook's settings page.
+    Test {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Webhook ID
+        #[arg()]
+        id: u64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DeployKeyCommands {
+    /// List deploy keys
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Add a deploy key from a file, or generate a new ed25519 key pair
+    Add {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Key title [default: <repository> deploy key]
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Public key file to add
+        #[arg(
+            short,
+            long,
+            required_unless_present = "generate",
+            conflicts_with = "generate"
+        )]
+        key_file: Option<PathBuf>,
+
+        /// Generate a key pair, with the private key in this file
+        #[arg(short, long)]
+        generate: Option<PathBuf>,
+
+        /// Allow pushing with the key, not just pulling
+        #[arg(short, long)]
+        write: bool,
+    },
+    /// Remove a deploy key
+    Remove {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Deploy key ID
+        #[arg()]
+        id: u64,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum Permission {
+    Read,
+    Write,
+    Admin,
+}
+
+#[derive(Subcommand)]
+pub enum AccessCommands {
+    /// List the collaborators and teams with access to a repository
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Add a collaborator, or give an organization team access
+    Add {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// User name, or team name with --team
+        #[arg()]
+        name: String,
+
+        /// Collaborator permission [default: write]
+        #[arg(short('P'), long, conflicts_with = "team")]
+        permission: Option<Permission>,
+
+        /// The name is an organization team; its access is set by the team
+        #[arg(short, long)]
+        team: bool,
+    },
+    /// Remove a collaborator, or an organization team's access
+    Remove {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// User name, or team name with --team
+        #[arg()]
+        name: String,
+
+        /// The name is an organization team
+        #[arg(short, long)]
+        team: bool,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Print who has what access to every repository of an organization
+    Audit {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Print comma separated values instead of a table
+        #[arg(long, conflicts_with = "json")]
+        csv: bool,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum Visibility {
+    Public,
+    Limited,
+    Private,
+}
+
+#[derive(Subcommand)]
+pub enum OrgCommands {
+    /// List the organizations you belong to
+    List {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// List every organization on the server instead
+        #[arg(short, long)]
+        all: bool,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show an organization
+    View {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create an organization
+    Create {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Full name
+        #[arg(short, long)]
+        full_name: Option<String>,
+
+        /// Description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Website
+        #[arg(short, long)]
+        website: Option<String>,
+
+        /// Location
+        #[arg(short, long)]
+        location: Option<String>,
+
+        /// Who can see the organization [default: public]
+        #[arg(short('V'), long)]
+        visibility: Option<Visibility>,
+    },
+    /// Change an organization's settings
+    Edit {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// New full name
+        #[arg(short, long)]
+        full_name: Option<String>,
+
+        /// New description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// New website
+        #[arg(short, long)]
+        website: Option<String>,
+
+        /// New location
+        #[arg(short, long)]
+        location: Option<String>,
+
+        /// Who can see the organization
+        #[arg(short('V'), long)]
+        visibility: Option<Visibility>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TeamCommands {
+    /// List the teams of an organization
+    List {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a team
+    Create {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Team name
+        #[arg()]
+        team: String,
+
+        /// Description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Access the team has to its repositories [default: read]
+        #[arg(short('P'), long)]
+        permission: Option<Permission>,
+
+        /// Give the team access to every repository of the organization
+        #[arg(short, long)]
+        all_repos: bool,
+
+        /// Allow members to create repositories in the organization
+        #[arg(short, long)]
+        can_create_repos: bool,
+    },
+    /// List the members of a team
+    Members {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Team name
+        #[arg()]
+        team: String,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Add users to a team
+    AddMember {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Team name
+        #[arg()]
+        team: String,
+
+        /// User names
+        #[arg(required = true)]
+        users: Vec<String>,
+    },
+    /// Remove users from a team
+    RemoveMember {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Team name
+        #[arg()]
+        team: String,
+
+        /// User names
+        #[arg(required = true)]
+        users: Vec<String>,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Give a team access to repositories of its organization
+    AddRepo {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Organization
+        #[arg()]
+        org: String,
+
+        /// Team name
+        #[arg()]
+        team: String,
+
+        /// Repository names, without the organization
+        #[arg(required = true)]
+        repos: Vec<String>,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum PushMode {
+    /// Nobody can push; changes come through pull requests
+    None,
+    /// Only whitelisted users and teams can push
+    Whitelist,
+    /// Anyone with write access can push
+    All,
+}
+
+// The settings of a branch protection rule; those not specified are left alone
+#[derive(Args)]
+pub struct RuleArgs {
+    /// Number of approvals a pull request needs
+    #[arg(short, long)]
+    pub(crate) approvals: Option<i64>,
+
+    /// Status check that must pass (may be repeated)
+    #[arg(short('c'), long("status-check"), conflicts_with = "no_status_checks")]
+    pub(crate) status_checks: Vec<String>,
+
+    /// Don't require status checks
+    #[arg(long)]
+    pub(crate) no_status_checks: bool,
+
+    /// Who can push to matching branches
+    #[arg(short, long)]
+    pub(crate) push: Option<PushMode>,
+
+    /// User who can push (may be repeated)
+    #[arg(long("push-user"))]
+    pub(crate) push_users: Vec<String>,
+
+    /// Team who can push (may be repeated)
+    #[arg(long("push-team"))]
+    pub(crate) push_teams: Vec<String>,
+
+    /// User who can merge pull requests (may be repeated)
+    #[arg(long("merge-user"), conflicts_with = "no_merge_whitelist")]
+    pub(crate) merge_users: Vec<String>,
+
+    /// Team who can merge pull requests (may be repeated)
+    #[arg(long("merge-team"), conflicts_with = "no_merge_whitelist")]
+    pub(crate) merge_teams: Vec<String>,
+
+    /// Anyone with write access can merge pull requests
+    #[arg(long)]
+    pub(crate) no_merge_whitelist: bool,
+
+    /// Commits must be signed
+    #[arg(long, conflicts_with = "not_signed")]
+    pub(crate) signed: bool,
+
+    /// Commits need not be signed
+    #[arg(long, conflicts_with = "signed")]
+    pub(crate) not_signed: bool,
+
+    /// Pull requests with rejected reviews can't be merged
+    #[arg(long, conflicts_with = "no_block_on_rejected")]
+    pub(crate) block_on_rejected: bool,
+
+    /// Pull requests with rejected reviews can be merged
+    #[arg(long, conflicts_with = "block_on_rejected")]
+    pub(crate) no_block_on_rejected: bool,
+}
+
+#[derive(Subcommand)]
+pub enum ProtectCommands {
+    /// List branch protection rules
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create or change the rule for a branch pattern
+    Set {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Branch name or glob pattern, e.g. release/*
+        #[arg()]
+        pattern: String,
+
+        #[command(flatten)]
+        rule_args: RuleArgs,
+    },
+    /// Remove the rule for a branch pattern
+    Remove {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Branch name or glob pattern
+        #[arg()]
+        pattern: String,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Write the rules to a YAML or JSON file
+    Export {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// File to write [default: standard output]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// File format [default: from the file extension, or yaml]
+        #[arg(short, long, value_enum)]
+        format: Option<FileFormat>,
+    },
+    /// Create or change rules from a file, in one or more repositories
+    Apply {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// YAML or JSON rule file, as written by export
+        #[arg()]
+        file: PathBuf,
+
+        /// Repository to apply the rules to (owner/name, may be repeated) [default: the current repository]
+        #[arg(short, long("target"))]
+        targets: Vec<RepoName>,
+    },
+}
+
+// Selects stale branches. The default branch and protected branches are never selected.
+#[derive(Args)]
+pub struct BranchFilterArgs {
+    /// Only branches with no commits that aren't in the base branch
+    #[arg(short, long)]
+    pub(crate) merged: bool,
+
+    /// Only branches whose last commit is older than this many days
+    #[arg(short, long, value_name = "DAYS")]
+    pub(crate) older_than: Option<u64>,
+
+    /// Base branch for --merged [default: the repository's default branch]
+    #[arg(short, long)]
+    pub(crate) base: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum BranchCommands {
+    /// List branches with their last commit
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        #[command(flatten)]
+        filter_args: BranchFilterArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a branch
+    Create {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Branch name
+        #[arg()]
+        name: String,
+
+        /// Branch to start from [default: the repository's default branch]
+        #[arg(short, long)]
+        from: Option<String>,
+    },
+    /// Delete branches by name, or every branch matching the filters
+    Delete {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Branch names
+        #[arg(required_unless_present_any = ["merged", "older_than"])]
+        names: Vec<String>,
+
+        #[command(flatten)]
+        filter_args: BranchFilterArgs,
+
+        /// Only report which branches would be deleted
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TagCommands {
+    /// List tags
+    List {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a tag
+    Create {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Tag name
+        #[arg()]
+        name: String,
+
+        /// Branch or commit to tag [default: the repository's default branch]
+        #[arg(short, long)]
+        target: Option<String>,
+
+        /// Message, which makes it an annotated tag
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// Delete tags
+    Delete {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Tag names
+        #[arg(required = true)]
+        names: Vec<String>,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TopicsCommands {
+    /// Print a repository's topics
+    Get {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Print JSON instead of a list
+        #[arg(long)]
+        json: bool,
+    },
+    /// Replace a repository's topics; with no topics, remove them all
+    Set {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Topics
+        #[arg()]
+        topics: Vec<String>,
+    },
+    /// Add topics to a repository
+    Add {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Topics
+        #[arg(required = true)]
+        topics: Vec<String>,
+    },
+    /// Remove topics from a repository
+    Remove {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Topics
+        #[arg(required = true)]
+        topics: Vec<String>,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum CommitState {
+    Pending,
+    Success,
+    Failure,
+    Error,
+    Warning,
+}
+
+#[derive(Subcommand)]
+pub enum StatusCommands {
+    /// Set the status of a commit for a context
+    Set {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Commit SHA, or a local branch or tag [default: HEAD]
+        #[arg()]
+        sha: Option<String>,
+
+        /// Status
+        #[arg(short, long)]
+        state: CommitState,
+
+        /// What reported the status, e.g. ci/build [default: default]
+        #[arg(short, long)]
+        context: Option<String>,
+
+        /// Link to the details, e.g. the build log
+        #[arg(short('U'), long)]
+        url: Option<Url>,
+
+        /// Short description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    /// Show the combined status of a commit and the status of each context
+    Get {
+        #[command(flatten)]
+        repo_args: RepoArgs,
+
+        /// Branch, tag or commit SHA [default: the SHA of the local HEAD]
+        #[arg(name = "REF")]
+        reference: Option<String>,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TokenCommands {
+    /// List your access tokens
+    List {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// One-time password, if the account has two-factor authentication
+        #[arg(long)]
+        otp: Option<String>,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create an access token, and print it
+    Create {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Token name
+        #[arg()]
+        name: String,
+
+        /// Scope, e.g. write:repository or read:user (may be repeated)
+        #[arg(short, long("scope"), required = true)]
+        scopes: Vec<String>,
+
+        /// One-time password, if the account has two-factor authentication
+        #[arg(long)]
+        otp: Option<String>,
+
+        /// Also save the token in the encrypted token store, for gtrepo to use
+        #[arg(long)]
+        save: bool,
+    },
+    /// Delete an access token
+    Delete {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Token name or ID
+        #[arg()]
+        name: String,
+
+        /// One-time password, if the account has two-factor authentication
+        #[arg(long)]
+        otp: Option<String>,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum TokenStorage {
+    /// The config file
+    Config,
+    /// The encrypted token store
+    Store,
+    /// The git credential helper, as the password
+    Helper,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommands {
+    /// Check an access token against a Gitea server, and store it
+    Login {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Where to store the token
+        #[arg(short, long, default_value = "config")]
+        storage: TokenStorage,
+
+        /// Log in in the browser, e.g. with single sign-on, instead of with an access token
+        #[arg(short, long)]
+        web: bool,
+
+        /// Client ID of the OAuth2 application to log in with on the web [default: from the config file]
+        #[arg(long, requires = "web")]
+        client_id: Option<String>,
+
+        /// Port to listen on for the browser's redirect [default: any free port]
+        #[arg(long, requires = "web", default_value_t = 0, hide_default_value = true)]
+        port: u16,
+    },
+    /// Forget the access token for a Gitea server
+    Logout {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Where to remove the token from [default: the config file and the token store, or else the git credential helper]
+        #[arg(short, long)]
+        storage: Option<TokenStorage>,
+    },
+    /// Show which user is authenticated on a Gitea server
+    Status {
+        #[command(flatten)]
+        server_args: ServerArgs,
+
+        /// Show every server in the config file and the token store
+        #[arg(short, long)]
+        all: bool,
+    },
+}
+
+#[derive(Args)]
+pub struct ApiArgs {
+    #[command(flatten)]
+    pub(crate) repo_args: RepoArgs,
+
+    /// HTTP method, e.g. GET, POST, PATCH, PUT or DELETE
+    #[arg()]
+    pub(crate) method: String,
+
+    /// Path of the endpoint after /api/v1/, e.g. repos/{owner}/{repo}/issues. {owner} and {repo} are replaced with the repository's owner and name
+    #[arg(value_name = "PATH")]
+    pub(crate) endpoint: String,
+
+    /// Parameter with a string value (may be repeated). GET requests send it in the query string, others in the JSON body; key[]=value adds to a list
+    #[arg(short, long("field"), value_name = "KEY=VALUE")]
+    pub(crate) fields: Vec<String>,
+
+    /// Parameter with a JSON value, e.g. true, 3 or null (may be repeated)
+    #[arg(short('F'), long("typed-field"), value_name = "KEY=VALUE")]
+    pub(crate) typed_fields: Vec<String>,
+
+    /// File with the JSON body of the request, or - for standard input. The parameters are then sent in the query string
+    #[arg(long)]
+    pub(crate) input: Option<PathBuf>,
+
+    /// Get every page of a list, and print them as one list
+    #[arg(long)]
+    pub(crate) paginate: bool,
+
+    /// Print the response as it is, instead of pretty printed
+    #[arg(long)]
+    pub(crate) raw: bool,
 }
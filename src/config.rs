@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::app_error::AppError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+// Settings per Gitea server, keyed by the server's URL, e.g.
+//
+// servers:
+//   https://gitea.example.com/:
+//     token: 0123456789abcdef
+#[derive(Default, Deserialize, Serialize)]
+pub(crate) struct Config {
+    #[serde(default)]
+    servers: BTreeMap<String, ServerConfig>,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub(crate) struct ServerConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    // The OAuth2 application to log in with on the web
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    // A token from a web login expires, and is refreshed with this
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    // Seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    // TLS and proxy settings, like the command line options of the same names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub insecure: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+}
+
+impl Config {
+    // A missing config file is the same as an empty one
+    pub fn load() -> Result<Config, AppError> {
+        let path = config_file()?;
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let text = std::fs::read_to_string(&path)?;
+        serde_yaml::from_str::<Config>(text.as_str()).map_err(|error| {
+            AppError::from(format!("Could not read {}: {}", path.display(), error))
+        })
+    }
+
+    pub fn save(&self) -> Result<(), AppError> {
+        let text =
+            serde_yaml::to_string(self).map_err(|error| AppError::from(error.to_string()))?;
+        write_private(&config_file()?, text.as_bytes())
+    }
+
+    pub fn servers_with_tokens(&self) -> impl Iterator<Item = &String> {
+        self.servers
+            .iter()
+            .filter(|(_, server)| server.token.is_some())
+            .map(|(key, _)| key)
+    }
+
+    // Adds the server if it isn't in the config yet
+    pub fn server_mut(&mut self, gitea_url: &Url) -> &mut ServerConfig {
+        let key = self
+            .servers
+            .keys()
+            .find(|key| same_server(key, gitea_url))
+            .cloned()
+            .unwrap_or_else(|| gitea_url.to_string());
+        self.servers.entry(key).or_default()
+    }
+
+    // Removes the server's token, and returns it if there was one
+    pub fn take_token(&mut self, gitea_url: &Url) -> Option<String> {
+        let (_, server) = self
+            .servers
+            .iter_mut()
+            .find(|(key, _)| same_server(key, gitea_url))?;
+        server.refresh_token = None;
+        server.expires_at = None;
+        server.token.take()
+    }
+
+    pub fn server(&self, gitea_url: &Url) -> Option<&ServerConfig> {
+        self.servers
+            .iter()
+            .find(|(key, _)| same_server(key, gitea_url))
+            .map(|(_, server)| server)
+    }
+}
+
+// The directory for gtrepo's files, e.g. ~/.config/gtrepo, or GTREPO_CONFIG_DIR if it's set
+pub(crate) fn config_dir() -> Result<PathBuf, AppError> {
+    if let Some(dir) = std::env::var_os("GTREPO_CONFIG_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    dirs::config_dir()
+        .map(|dir| dir.join("gtrepo"))
+        .ok_or_else(|| AppError::from("Could not find the configuration directory"))
+}
+
+pub(crate) fn config_file() -> Result<PathBuf, AppError> {
+    Ok(config_dir()?.join("config.yaml"))
+}
+
+// Server keys may be written with or without a trailing slash
+pub(crate) fn same_server(key: &str, gitea_url: &Url) -> bool {
+    key.trim_end_matches('/') == gitea_url.as_str().trim_end_matches('/')
+}
+
+// Files with secrets in them are only readable by the user. They're written to
+// a new file that's renamed over the old one, so an existing file's permissions
+// don't carry over, and a crash can't leave it half written.
+pub(crate) fn write_private(path: &Path, contents: &[u8]) -> Result<(), AppError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&temp_path)?;
+    // The mode only applies to a new file, and one may be left from a crash
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
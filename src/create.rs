@@ -3,10 +3,18 @@
  * Created 2024-03-13
  */
 
-use crate::{app_error::AppError, create::create_parameters::CreateParameters};
+use crate::{
+    app_error::AppError,
+    create::create_parameters::CreateParameters,
+    gitea_client::{authenticated_client, GiteaClient},
+    models::Repository,
+    prompt::confirm,
+    protect::{apply_rules, read_rule_file},
+    repo_context::RepoContext,
+    repo_name::RepoName,
+};
 use git_lib::GitLib;
-use gitea_api::{CreateRepoOptions, GiteaApi, Repository, TrustModel};
-use std::io::{BufRead, Write};
+use serde::Serialize;
 use std::path::PathBuf;
 use url::Url;
 
@@ -25,7 +33,13 @@ pub(crate) async fn create(
     not_private: &bool,
     template: &bool,
     not_template: &bool,
+    protection: &Option<PathBuf>,
 ) -> Result<(), AppError> {
+    // A bad rule file is found before anything is created
+    let rules = match protection {
+        Some(protection) => read_rule_file(protection)?,
+        None => Vec::new(),
+    };
     let create_parameters = CreateParameters::prompt_for_missing(
         path,
         gitea_url,
@@ -39,38 +53,49 @@ pub(crate) async fn create(
     {
         // println!("{:?}", create_parameters);
         if !confirm()? {
-            return Err(AppError::from("Canceled"));
+            return Err(AppError::Canceled);
         }
         // Create the remote repository
-        let repository = create_repository(&create_parameters).await?;
+        let gitea_client = authenticated_client(create_parameters.gitea_url()).await?;
+        let repository = create_repository(&gitea_client, &create_parameters).await?;
         crate::print_success!("Created remote repository: {}", repository.clone_url);
 
-        // Add the remote to the local repository
-        if let Err(error) = GitLib::remote_add(
+        // Add the remote to the local repository before protecting the branches,
+        // so the checkout is usable even if a protection rule fails
+        GitLib::remote_add(
             create_parameters.remote_name(),
             repository.clone_url.as_str(),
             Some(create_parameters.path()),
-        ) {
-            Err(AppError::from(error.to_string()))
-        } else {
-            crate::print_success!(
-                "Tracking remote repository locally as: {}",
-                create_parameters.remote_name()
-            );
-            crate::print_info!(
-                "Push: git push -u {} {}",
-                create_parameters.remote_name(),
-                repository.default_branch
-            );
-            Ok(())
+        )
+        .map_err(|error| AppError::Git(error.to_string()))?;
+        crate::print_success!(
+            "Tracking remote repository locally as: {}",
+            create_parameters.remote_name()
+        );
+        crate::print_info!(
+            "Push: git push -u {} {}",
+            create_parameters.remote_name(),
+            repository.default_branch
+        );
+
+        // Protect its branches
+        if !rules.is_empty() {
+            let repo_name = repository
+                .full_name
+                .parse::<RepoName>()
+                .map_err(AppError::from)?;
+            let repo_context = RepoContext::new(create_parameters.gitea_url().clone(), &repo_name);
+            apply_rules(&gitea_client, &repo_context, &rules).await?;
         }
+
+        Ok(())
     }
 }
 
 // If the negative option was specified, the flag is false.
 // Otherwise, if the positive option was specified, the flag is true.
 // If neither option was specified, the flag is unspecified.
-fn bool_option(negative: &bool, positive: &bool) -> Option<bool> {
+pub(crate) fn bool_option(negative: &bool, positive: &bool) -> Option<bool> {
     if *negative {
         Some(false)
     } else if *positive {
@@ -80,55 +105,43 @@ fn bool_option(negative: &bool, positive: &bool) -> Option<bool> {
     }
 }
 
-fn confirm() -> Result<bool, std::io::Error> {
-    let mut bucket = String::with_capacity(2048);
-    let mut stdin = std::io::stdin().lock();
-    let mut stdout = std::io::stdout().lock();
-
-    // We do this because stdout may be buffered
-    write!(stdout, "☑️ Continue? [Y/n]: ")?;
-    stdout.flush()?;
-
-    stdin.read_line(&mut bucket)?;
-    let result = bucket.trim_end_matches('\n').trim_end_matches('\r');
-    Ok(result.is_empty() || result.to_lowercase() == "y")
+// Gitea's API for creating a repository. This goes through GiteaClient rather than
+// gitea-api, whose HTTP client can't be given the TLS and proxy settings.
+#[derive(Serialize)]
+struct CreateRepoOption<'a> {
+    name: &'a str,
+    default_branch: &'a str,
+    trust_model: &'a str,
+    auto_init: bool,
+    private: bool,
+    template: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
 }
 
-async fn create_repository(parameters: &CreateParameters) -> Result<Repository, AppError> {
-    let url = parameters.gitea_url().as_str();
-    // Need credentials for the Gitea server
-    match GitLib::credentials_fill(url) {
-        Ok(credentials) => {
-            let gitea_api = GiteaApi::new(
-                url,
-                credentials.username().as_deref(),
-                credentials.password().as_deref(),
-            );
-            let create_repo_options = create_repo_options(parameters);
-            Ok(gitea_api.create_repo(&create_repo_options).await?)
-        }
-        Err(error) => Err(AppError::from(error.to_string())),
-    }
+async fn create_repository(
+    gitea_client: &GiteaClient,
+    parameters: &CreateParameters,
+) -> Result<Repository, AppError> {
+    gitea_client
+        .post::<_, Repository>("user/repos", &create_repo_options(parameters))
+        .await
 }
 
-fn create_repo_options(create_parameters: &CreateParameters) -> CreateRepoOptions {
+fn create_repo_options(create_parameters: &CreateParameters) -> CreateRepoOption<'_> {
     let description = if create_parameters.description().is_empty() {
         None
     } else {
-        Some(create_parameters.description().to_string())
+        Some(create_parameters.description().as_str())
     };
 
-    CreateRepoOptions::new(
-        create_parameters.gitea_name().to_string(), // name: String,
-        create_parameters.default_branch().to_string(), // default_branch: String,
-        TrustModel::Default,                        // trust_model: TrustModel,
-        false,                              // auto_init: bool,
-        *create_parameters.private(),               // private: bool,
-        *create_parameters.template(),              // template: bool,
-        description,                                // description: Option<String>,
-        None,                                       // gitignores: Option<String>,
-        None,                                       // issue_labels: Option<String>,
-        None,                                       // license: Option<String>,
-        None,                                       // readme: Option<String>,
-    )
+    CreateRepoOption {
+        name: create_parameters.gitea_name(),
+        default_branch: create_parameters.default_branch(),
+        trust_model: "default",
+        auto_init: false,
+        private: *create_parameters.private(),
+        template: *create_parameters.template(),
+        description,
+    }
 }
@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    config::Config,
+    oauth::{expires_soon, refresh_token},
+    token_store::stored_token,
+};
+use git_lib::GitLib;
+use std::sync::OnceLock;
+use url::Url;
+
+// The --token option applies to whichever server the command talks to
+static TOKEN_OPTION: OnceLock<String> = OnceLock::new();
+
+pub(crate) fn set_token_option(token: &Option<String>) {
+    if let Some(token) = token {
+        let _ = TOKEN_OPTION.set(token.to_owned());
+    }
+}
+
+pub(crate) fn token_option() -> Option<String> {
+    TOKEN_OPTION.get().cloned()
+}
+
+// A token for the server from, in order, the --token option, the GITEA_TOKEN
+// environment variable, the config file, or the encrypted token store
+pub(crate) async fn token(gitea_url: &Url) -> Result<Option<String>, AppError> {
+    Ok(token_and_source(gitea_url).await?.map(|(token, _)| token))
+}
+
+// The same, along with where the token came from. A token from a web login
+// is refreshed first if it's about to expire.
+pub(crate) async fn token_and_source(
+    gitea_url: &Url,
+) -> Result<Option<(String, &'static str)>, AppError> {
+    if let Some(token) = TOKEN_OPTION.get() {
+        return Ok(Some((token.clone(), "the --token option")));
+    }
+    match std::env::var("GITEA_TOKEN") {
+        Ok(token) if !token.is_empty() => return Ok(Some((token, "GITEA_TOKEN"))),
+        _ => {}
+    }
+    if let Some(server) = Config::load()?.server(gitea_url) {
+        if let Some(token) = &server.token {
+            let token = if expires_soon(server) {
+                refresh_token(gitea_url).await?
+            } else {
+                token.clone()
+            };
+            return Ok(Some((token, "the config file")));
+        }
+    }
+    Ok(stored_token(gitea_url)?.map(|token| (token, "the token store")))
+}
+
+// The username and password the git credential helper has for the server
+pub(crate) fn password(gitea_url: &Url) -> Result<(Option<String>, Option<String>), AppError> {
+    match GitLib::credentials_fill(gitea_url.as_str()) {
+        Ok(credentials) => Ok((
+            credentials.username().as_deref().map(str::to_string),
+            credentials.password().as_deref().map(str::to_string),
+        )),
+        Err(error) => Err(AppError::Git(error.to_string())),
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{app_error::AppError, command_line_arguments::FileFormat};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+// Label and branch protection rule files are lists in YAML or JSON. The format
+// comes from the file's extension, and is YAML unless it's .json.
+
+// What the file holds, e.g. labels, is for the error message
+pub(crate) fn read_data_file<T: DeserializeOwned>(
+    file: &Path,
+    what: &str,
+) -> Result<Vec<T>, AppError> {
+    let result = std::fs::read_to_string(file)
+        .map_err(|error| error.to_string())
+        .and_then(|text| match file_format(file) {
+            FileFormat::Json => {
+                serde_json::from_str(text.as_str()).map_err(|error| error.to_string())
+            }
+            FileFormat::Yaml => {
+                serde_yaml::from_str(text.as_str()).map_err(|error| error.to_string())
+            }
+        });
+    result.map_err(|error| {
+        AppError::from(format!(
+            "Could not read {} from {}: {}",
+            what,
+            file.display(),
+            error
+        ))
+    })
+}
+
+// Writes the list to the output file, or prints it if there isn't one
+pub(crate) fn write_data_file<T: Serialize>(
+    items: &[T],
+    output: &Option<PathBuf>,
+    format: &Option<FileFormat>,
+) -> Result<(), AppError> {
+    let format = match (format, output) {
+        (Some(format), _) => format.clone(),
+        (None, Some(output)) => file_format(output),
+        (None, None) => FileFormat::Yaml,
+    };
+    let text = match format {
+        FileFormat::Yaml => {
+            serde_yaml::to_string(items).map_err(|error| AppError::from(error.to_string()))?
+        }
+        FileFormat::Json => serde_json::to_string_pretty(items)
+            .map_err(|error| AppError::from(error.to_string()))?,
+    };
+    match output {
+        Some(output) => std::fs::write(output, text)?,
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+fn file_format(path: &Path) -> FileFormat {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => FileFormat::Json,
+        _ => FileFormat::Yaml,
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError, command_line_arguments::DeployKeyCommands,
+    gitea_client::authenticated_client, output::print_json, prompt::confirm,
+    repo_context::RepoContext, table::Table,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Deserialize, Serialize)]
+struct DeployKey {
+    id: u64,
+    title: String,
+    key: String,
+    #[serde(default)]
+    fingerprint: String,
+    read_only: bool,
+    created_at: String,
+}
+
+#[derive(Serialize)]
+struct CreateKeyOption<'a> {
+    title: &'a str,
+    key: &'a str,
+    read_only: bool,
+}
+
+pub(crate) async fn deploy_key(command: &DeployKeyCommands) -> Result<(), AppError> {
+    match command {
+        DeployKeyCommands::List { repo_args, json } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let keys = gitea_client
+                .get_all::<DeployKey>(repo_context.api_path("/keys").as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&keys)
+            } else if keys.is_empty() {
+                crate::print_info!("{} has no deploy keys", repo_context.repo());
+                Ok(())
+            } else {
+                let mut table = Table::new(&["ID", "Access", "Created", "Fingerprint", "Title"]);
+                for key in keys.iter() {
+                    table.add_row(vec![
+                        key.id.to_string(),
+                        access(key.read_only),
+                        key.created_at.clone(),
+                        key.fingerprint.clone(),
+                        key.title.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        DeployKeyCommands::Add {
+            repo_args,
+            title,
+            key_file,
+            generate,
+            write,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let title = match title {
+                Some(title) => title.to_owned(),
+                None => format!("{} deploy key", repo_context.repo()),
+            };
+            let public_key_file = match (key_file, generate) {
+                (Some(key_file), _) => key_file.to_owned(),
+                (None, Some(private_key_file)) => generate_key(private_key_file, title.as_str())?,
+                (None, None) => {
+                    return Err(AppError::from(
+                        "Either a key file or a file to generate a key in is required",
+                    ))
+                }
+            };
+            let key = std::fs::read_to_string(&public_key_file)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = CreateKeyOption {
+                title: title.as_str(),
+                key: key.trim(),
+                read_only: !*write,
+            };
+            let deploy_key = gitea_client
+                .post::<_, DeployKey>(repo_context.api_path("/keys").as_str(), &options)
+                .await?;
+            crate::print_success!(
+                "Added {} deploy key {} to {}",
+                access(deploy_key.read_only),
+                deploy_key.id,
+                repo_context.repo()
+            );
+            crate::print_info!("Fingerprint: {}", deploy_key.fingerprint);
+            Ok(())
+        }
+        DeployKeyCommands::Remove { repo_args, id, yes } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            crate::print_info!("Removing deploy key {} from {}", id, repo_context.repo());
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(
+                    repo_context
+                        .api_path(format!("/keys/{}", id).as_str())
+                        .as_str(),
+                )
+                .await?;
+            crate::print_success!("Removed deploy key {} from {}", id, repo_context.repo());
+            Ok(())
+        }
+    }
+}
+
+fn access(read_only: bool) -> String {
+    if read_only {
+        String::from("read-only")
+    } else {
+        String::from("read-write")
+    }
+}
+
+// Generates an ed25519 key pair without a passphrase using ssh-keygen,
+// and returns the path of the public key
+fn generate_key(private_key_file: &Path, comment: &str) -> Result<PathBuf, AppError> {
+    if private_key_file.exists() {
+        return Err(AppError::from(format!(
+            "{} already exists",
+            private_key_file.display()
+        )));
+    }
+    let output = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-C", comment, "-f"])
+        .arg(private_key_file)
+        .output()
+        .map_err(|error| AppError::from(format!("Could not run ssh-keygen: {}", error)))?;
+    if !output.status.success() {
+        return Err(AppError::from(format!(
+            "ssh-keygen failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let mut public_key_file = private_key_file.as_os_str().to_owned();
+    public_key_file.push(".pub");
+    crate::print_success!("Generated key pair {}", private_key_file.display());
+    Ok(PathBuf::from(public_key_file))
+}
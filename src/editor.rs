@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::app_error::AppError;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Lets the user edit some text in their editor, i.e. the one named by the
+// VISUAL or EDITOR environment variable. The editor may include arguments,
+// e.g. "code --wait".
+pub(crate) fn edit_text(initial: &str) -> Result<String, AppError> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| String::from(default_editor()));
+    let mut words = editor.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| AppError::from("The EDITOR environment variable is empty"))?;
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let path = std::env::temp_dir().join(format!("gtrepo-{}-{}.md", std::process::id(), nanos));
+    std::fs::write(&path, initial)?;
+
+    let status = Command::new(program).args(words).arg(&path).status();
+    let text = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+    match status {
+        Ok(status) if status.success() => Ok(text?.trim().to_string()),
+        Ok(status) => Err(AppError::from(format!(
+            "{} exited with {}",
+            program, status
+        ))),
+        Err(error) => Err(AppError::from(format!(
+            "Could not run editor '{}': {}",
+            program, error
+        ))),
+    }
+}
+
+fn default_editor() -> &'static str {
+    if cfg!(windows) {
+        "notepad"
+    } else {
+        "vi"
+    }
+}
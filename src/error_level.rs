@@ -3,11 +3,22 @@
  * Created 2024-03-09
  */
 
+use crate::{app_error::AppError, create::parameters_error::ParametersError};
+use reqwest::StatusCode;
 use std::process::ExitCode;
 
+// The exit codes are documented in the README, so scripts can rely on them
 pub enum ErrorLevel {
     Success = 0,
     Failure = 1,
+    // The same as clap's exit code for invalid arguments
+    Usage = 2,
+    Canceled = 3,
+    Authentication = 4,
+    NotFound = 5,
+    Conflict = 6,
+    Network = 7,
+    Git = 8,
 }
 
 impl From<ErrorLevel> for ExitCode {
@@ -15,3 +26,104 @@ impl From<ErrorLevel> for ExitCode {
         ExitCode::from(error_level as u8)
     }
 }
+
+impl From<&AppError> for ErrorLevel {
+    fn from(error: &AppError) -> Self {
+        match error {
+            AppError::Url(_) => ErrorLevel::Usage,
+            AppError::Parameter(error) => ErrorLevel::from(error),
+            AppError::Canceled => ErrorLevel::Canceled,
+            AppError::Other(_) | AppError::Io(_) => ErrorLevel::Failure,
+            AppError::Request(error) => match error.status() {
+                Some(status) => ErrorLevel::from(status),
+                None if error.is_connect() || error.is_timeout() || error.is_request() => {
+                    ErrorLevel::Network
+                }
+                None => ErrorLevel::Failure,
+            },
+            AppError::Status(status, _) => ErrorLevel::from(*status),
+            AppError::Git(_) => ErrorLevel::Git,
+            AppError::NotFound(_) => ErrorLevel::NotFound,
+        }
+    }
+}
+
+impl From<&ParametersError> for ErrorLevel {
+    fn from(error: &ParametersError) -> Self {
+        match error {
+            ParametersError::GitCommand(_) => ErrorLevel::Git,
+            ParametersError::Url(_) | ParametersError::Other(_) => ErrorLevel::Usage,
+            ParametersError::Io(_) => ErrorLevel::Failure,
+        }
+    }
+}
+
+impl From<StatusCode> for ErrorLevel {
+    fn from(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ErrorLevel::Authentication,
+            StatusCode::NOT_FOUND => ErrorLevel::NotFound,
+            StatusCode::CONFLICT => ErrorLevel::Conflict,
+            _ => ErrorLevel::Failure,
+        }
+    }
+}
+
+// The exit codes are documented, so they mustn't change by accident
+#[cfg(test)]
+mod tests {
+    use super::ErrorLevel;
+    use crate::{app_error::AppError, create::parameters_error::ParametersError};
+    use reqwest::StatusCode;
+
+    fn exit_code(error: AppError) -> u8 {
+        ErrorLevel::from(&error) as u8
+    }
+
+    #[test]
+    fn app_errors() {
+        assert_eq!(exit_code(AppError::from("failed")), 1);
+        assert_eq!(exit_code(AppError::Io(std::io::Error::other("failed"))), 1);
+        assert_eq!(exit_code(AppError::Url(url::ParseError::EmptyHost)), 2);
+        assert_eq!(exit_code(AppError::Canceled), 3);
+        assert_eq!(exit_code(AppError::NotFound(String::from("missing"))), 5);
+        assert_eq!(exit_code(AppError::Git(String::from("failed"))), 8);
+    }
+
+    #[test]
+    fn parameter_errors() {
+        assert_eq!(exit_code(AppError::from(ParametersError::from("bad"))), 2);
+        assert_eq!(
+            exit_code(AppError::from(ParametersError::from(
+                url::ParseError::EmptyHost
+            ))),
+            2
+        );
+        assert_eq!(
+            exit_code(AppError::from(ParametersError::from(
+                std::io::Error::other("failed")
+            ))),
+            1
+        );
+    }
+
+    #[test]
+    fn status_errors() {
+        let status_exit_code = |status| exit_code(AppError::Status(status, String::new()));
+        assert_eq!(status_exit_code(StatusCode::UNAUTHORIZED), 4);
+        assert_eq!(status_exit_code(StatusCode::FORBIDDEN), 4);
+        assert_eq!(status_exit_code(StatusCode::NOT_FOUND), 5);
+        assert_eq!(status_exit_code(StatusCode::CONFLICT), 6);
+        assert_eq!(status_exit_code(StatusCode::UNPROCESSABLE_ENTITY), 1);
+        assert_eq!(status_exit_code(StatusCode::INTERNAL_SERVER_ERROR), 1);
+    }
+
+    #[tokio::test]
+    async fn request_errors() {
+        // Nothing listens on port 1
+        let error = reqwest::get("http://127.0.0.1:1/").await.unwrap_err();
+        assert_eq!(exit_code(AppError::from(error)), 7);
+        let error = reqwest::get("not a url").await.unwrap_err();
+        assert_eq!(exit_code(AppError::from(error)), 1);
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::app_error::AppError;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+// Runs git in the given repository for operations git-lib doesn't provide,
+// and returns its trimmed output.
+pub(crate) fn git(path: &Path, args: &[&str]) -> Result<String, AppError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|error| AppError::Git(format!("Could not run git: {}", error)))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(AppError::Git(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+// Like git(), but lets git write to the terminal, e.g. for progress
+pub(crate) fn git_interactive(path: &Path, args: &[&str]) -> Result<(), AppError> {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(args)
+        .status()
+        .map_err(|error| AppError::Git(format!("Could not run git: {}", error)))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(AppError::Git(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            status
+        )))
+    }
+}
+
+pub(crate) fn current_branch(path: &Path) -> Result<String, AppError> {
+    let branch = git(path, &["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .map_err(|_| AppError::Git(String::from("HEAD is not on a branch")))?;
+    Ok(branch)
+}
+
+// The full SHA of a commit, e.g. HEAD
+pub(crate) fn rev_parse(path: &Path, rev: &str) -> Result<String, AppError> {
+    git(
+        path,
+        &[
+            "rev-parse",
+            "--verify",
+            format!("{}^{{commit}}", rev).as_str(),
+        ],
+    )
+}
+
+// A full SHA is used as is. Anything else, e.g. a branch name, is looked up
+// in the local repository.
+pub(crate) fn resolve_commit(path: &Path, rev: &str) -> Result<String, AppError> {
+    if rev.len() == 40 && rev.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(rev.to_owned())
+    } else {
+        rev_parse(path, rev)
+    }
+}
+
+// Tells the git credential helper to store (approve) or forget (reject) the
+// credentials described by the fields, e.g. url, username and password
+pub(crate) fn credential(action: &str, fields: &[(&str, &str)]) -> Result<(), AppError> {
+    let mut child = Command::new("git")
+        .args(["credential", action])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| AppError::Git(format!("Could not run git: {}", error)))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        for (key, value) in fields.iter() {
+            writeln!(stdin, "{}={}", key, value)?;
+        }
+        writeln!(stdin)?;
+    }
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(AppError::Git(format!(
+            "git credential {} failed: {}",
+            action,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
@@ -0,0 +1,335 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    credentials::{password, token},
+    http_client::http_client,
+};
+use reqwest::{multipart::Form, Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+// A client for the Gitea REST API, authenticated with a token, or else with a
+// username and password
+pub(crate) struct GiteaClient {
+    api_url: Url,
+    client: reqwest::Client,
+    username: Option<String>,
+    password: Option<String>,
+    token: Option<String>,
+    otp: Option<String>,
+}
+
+// Items per page to ask list endpoints for
+const PAGE_LIMIT: usize = 50;
+
+// Gitea reports errors as a JSON object with a message
+#[derive(Deserialize)]
+struct ErrorMessage {
+    message: String,
+}
+
+// Repository search wraps its results in an object with an ok flag
+#[derive(Deserialize)]
+struct SearchResult<T> {
+    ok: bool,
+    data: Vec<T>,
+}
+
+impl GiteaClient {
+    pub fn new(
+        gitea_url: &Url,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<GiteaClient, AppError> {
+        // Url::join replaces the last path segment unless the base ends with a slash
+        let mut base_url = gitea_url.clone();
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(format!("{}/", base_url.path()).as_str());
+        }
+        Ok(GiteaClient {
+            api_url: base_url.join("api/v1/")?,
+            client: http_client(gitea_url)?,
+            username: username.map(str::to_string),
+            password: password.map(str::to_string),
+            token: None,
+            otp: None,
+        })
+    }
+
+    pub fn with_token(gitea_url: &Url, token: &str) -> Result<GiteaClient, AppError> {
+        let mut gitea_client = GiteaClient::new(gitea_url, None, None)?;
+        gitea_client.token = Some(token.to_string());
+        Ok(gitea_client)
+    }
+
+    // A one-time password for accounts with two-factor authentication.
+    // Gitea only checks it along with a username and password.
+    pub fn with_otp(mut self, otp: &Option<String>) -> GiteaClient {
+        self.otp = otp.to_owned();
+        self
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, AppError> {
+        let response = self
+            .send(self.request(Method::GET, path)?.query(query))
+            .await?;
+        Ok(response.json::<T>().await?)
+    }
+
+    // For endpoints that return plain text, e.g. diffs
+    pub async fn get_text(&self, path: &str) -> Result<String, AppError> {
+        let response = self.send(self.request(Method::GET, path)?).await?;
+        Ok(response.text().await?)
+    }
+
+    // Gets every page of a list endpoint. Some lists ignore the page, so without
+    // a total count to stop at, only the first page is used.
+    pub async fn get_all<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<Vec<T>, AppError> {
+        let mut items = Vec::new();
+        let mut page = 1;
+        loop {
+            let (mut page_items, total_count) = self.get_page::<Vec<T>>(path, query, page).await?;
+            let empty = page_items.is_empty();
+            items.append(&mut page_items);
+            if empty || total_count.is_none_or(|total_count| items.len() >= total_count) {
+                return Ok(items);
+            }
+            page += 1;
+        }
+    }
+
+    // Gets every page of a repository search
+    pub async fn search_repos<T: DeserializeOwned>(
+        &self,
+        query: &[(&str, String)],
+    ) -> Result<Vec<T>, AppError> {
+        let mut repositories = Vec::new();
+        let mut page = 1;
+        loop {
+            let (mut result, total_count) = self
+                .get_page::<SearchResult<T>>("repos/search", query, page)
+                .await?;
+            if !result.ok {
+                return Err(AppError::from("Failed to get repositories"));
+            }
+            let empty = result.data.is_empty();
+            repositories.append(&mut result.data);
+            if empty || total_count.is_none_or(|total_count| repositories.len() >= total_count) {
+                return Ok(repositories);
+            }
+            page += 1;
+        }
+    }
+
+    // Gets a page of a list endpoint, and the total number of items if the server
+    // says. The server may send fewer items than the limit, if its maximum is lower,
+    // so a short page doesn't mean there are no more.
+    pub async fn get_page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        page: usize,
+    ) -> Result<(T, Option<usize>), AppError> {
+        let mut page_query = query.to_vec();
+        page_query.push(("page", page.to_string()));
+        page_query.push(("limit", PAGE_LIMIT.to_string()));
+        let response = self
+            .send(self.request(Method::GET, path)?.query(&page_query))
+            .await?;
+        let total_count = response
+            .headers()
+            .get("X-Total-Count")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok());
+        Ok((response.json::<T>().await?, total_count))
+    }
+
+    pub async fn post<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, AppError> {
+        let response = self
+            .send(self.request(Method::POST, path)?.json(body))
+            .await?;
+        Ok(response.json::<T>().await?)
+    }
+
+    // For endpoints that don't return anything useful
+    pub async fn post_empty<B: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<(), AppError> {
+        self.send(self.request(Method::POST, path)?.json(body))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn post_multipart<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        form: Form,
+    ) -> Result<T, AppError> {
+        let response = self
+            .send(
+                self.request(Method::POST, path)?
+                    .query(query)
+                    .multipart(form),
+            )
+            .await?;
+        Ok(response.json::<T>().await?)
+    }
+
+    pub async fn patch<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, AppError> {
+        let response = self
+            .send(self.request(Method::PATCH, path)?.json(body))
+            .await?;
+        Ok(response.json::<T>().await?)
+    }
+
+    // Gitea answers PUT requests with no content
+    pub async fn put<B: Serialize + ?Sized>(&self, path: &str, body: &B) -> Result<(), AppError> {
+        self.send(self.request(Method::PUT, path)?.json(body))
+            .await?;
+        Ok(())
+    }
+
+    // For any endpoint, e.g. from the api command. The body is JSON, and the
+    // response is returned as it is.
+    pub async fn send_text(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<String, AppError> {
+        let mut request = self.request(method, path)?.query(query);
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+        let response = self.send(request).await?;
+        Ok(response.text().await?)
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<(), AppError> {
+        self.send(self.request(Method::DELETE, path)?).await?;
+        Ok(())
+    }
+
+    // Downloads from a URL outside the API, e.g. a release attachment
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>, AppError> {
+        let request = self.authorize(self.client.get(url));
+        let response = self.send(request).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder, AppError> {
+        let url = self.api_url.join(path.trim_start_matches('/'))?;
+        // A path that is really a URL mustn't send the credentials anywhere else
+        if url.origin() != self.api_url.origin() {
+            return Err(AppError::from(format!(
+                "'{}' is not on the Gitea server",
+                path
+            )));
+        }
+        Ok(self.authorize(self.client.request(method, url)))
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        let request = match (&self.token, &self.username) {
+            (Some(token), _) => request.header("Authorization", format!("token {}", token)),
+            (None, Some(username)) => request.basic_auth(username, self.password.as_ref()),
+            (None, None) => request,
+        };
+        match &self.otp {
+            Some(otp) => request.header("X-Gitea-OTP", otp),
+            None => request,
+        }
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response, AppError> {
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let text = response.text().await.unwrap_or_default();
+            let message = match serde_json::from_str::<ErrorMessage>(text.as_str()) {
+                Ok(error_message) => error_message.message,
+                Err(_) => text,
+            };
+            Err(AppError::Status(status, message))
+        }
+    }
+}
+
+// Uses the Gitea URL if it was specified, otherwise the value in the
+// GITEA_URL environment variable if it exists.
+pub(crate) fn resolve_gitea_url(gitea_url: &Option<Url>) -> Result<Url, AppError> {
+    match gitea_url {
+        Some(gitea_url) => Ok(gitea_url.to_owned()),
+        None => {
+            let gitea_url_str = std::env::var("GITEA_URL").unwrap_or_default();
+            if let Ok(gitea_url) = Url::parse(gitea_url_str.as_str()) {
+                Ok(gitea_url)
+            } else {
+                // The url ParseError is not very meaningful
+                Err(AppError::from("Missing or invalid Gitea URL"))
+            }
+        }
+    }
+}
+
+// Gets a client using a token for the Gitea server if there is one, otherwise
+// the credentials the git credential helper has for it
+pub(crate) async fn authenticated_client(gitea_url: &Url) -> Result<GiteaClient, AppError> {
+    match token(gitea_url).await? {
+        Some(token) => GiteaClient::with_token(gitea_url, token.as_str()),
+        None => password_client(gitea_url),
+    }
+}
+
+// Some endpoints, e.g. the ones for managing tokens, need a username and password
+pub(crate) fn password_client(gitea_url: &Url) -> Result<GiteaClient, AppError> {
+    let (username, password) = password(gitea_url)?;
+    GiteaClient::new(gitea_url, username.as_deref(), password.as_deref())
+}
+
+// Names used in API paths, e.g. branch protection patterns, may contain
+// characters like '/' and '*' that must be percent-encoded
+pub(crate) fn path_segment(name: &str) -> String {
+    let mut segment = String::new();
+    for byte in name.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            segment.push(byte as char);
+        } else {
+            segment.push_str(format!("%{:02X}", byte).as_str());
+        }
+    }
+    segment
+}
@@ -0,0 +1,237 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{ContentType, HookCommands, HookType},
+    create::bool_option,
+    gitea_client::authenticated_client,
+    output::print_json,
+    prompt::confirm,
+    repo_context::{RepoContext, Scope},
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Deserialize, Serialize)]
+struct Hook {
+    id: u64,
+    #[serde(rename = "type")]
+    hook_type: String,
+    #[serde(default)]
+    config: HashMap<String, String>,
+    #[serde(default)]
+    events: Vec<String>,
+    active: bool,
+    #[serde(default)]
+    branch_filter: String,
+    #[serde(default)]
+    updated_at: String,
+}
+
+#[derive(Serialize)]
+struct CreateHookOption<'a> {
+    #[serde(rename = "type")]
+    hook_type: &'a str,
+    config: HashMap<&'a str, String>,
+    events: &'a Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch_filter: Option<&'a str>,
+    active: bool,
+}
+
+#[derive(Serialize)]
+struct EditHookOption<'a> {
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    config: HashMap<&'a str, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    events: &'a Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch_filter: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+pub(crate) async fn hook(command: &HookCommands) -> Result<(), AppError> {
+    match command {
+        HookCommands::List { scope_args, json } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let hooks = gitea_client
+                .get_all::<Hook>(scope.api_path("/hooks").as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&hooks)
+            } else if hooks.is_empty() {
+                crate::print_info!("{} has no webhooks", scope.name());
+                Ok(())
+            } else {
+                let mut table = Table::new(&["ID", "Type", "Active", "Branches", "Events", "URL"]);
+                for hook in hooks.iter() {
+                    table.add_row(vec![
+                        hook.id.to_string(),
+                        hook.hook_type.clone(),
+                        hook.active.to_string(),
+                        hook.branch_filter.clone(),
+                        hook.events.join(","),
+                        hook.config.get("url").cloned().unwrap_or_default(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        HookCommands::Create {
+            scope_args,
+            url,
+            hook_type,
+            events,
+            content_type,
+            secret,
+            branch_filter,
+            channel,
+            inactive,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let events = if events.is_empty() {
+                vec![String::from("push")]
+            } else {
+                events.to_owned()
+            };
+            let options = CreateHookOption {
+                hook_type: hook_type_str(hook_type),
+                config: config(
+                    &Some(url.to_string()),
+                    &Some(content_type.clone()),
+                    secret,
+                    channel,
+                ),
+                events: &events,
+                branch_filter: branch_filter.as_deref(),
+                active: !*inactive,
+            };
+            let hook = gitea_client
+                .post::<_, Hook>(scope.api_path("/hooks").as_str(), &options)
+                .await?;
+            crate::print_success!("Created webhook {} in {}", hook.id, scope.name());
+            Ok(())
+        }
+        HookCommands::Edit {
+            scope_args,
+            id,
+            url,
+            events,
+            content_type,
+            secret,
+            branch_filter,
+            channel,
+            active,
+            inactive,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let options = EditHookOption {
+                config: config(
+                    &url.as_ref().map(|url| url.to_string()),
+                    content_type,
+                    secret,
+                    channel,
+                ),
+                events,
+                branch_filter: branch_filter.as_deref(),
+                active: bool_option(inactive, active),
+            };
+            let hook = gitea_client
+                .patch::<_, Hook>(
+                    scope.api_path(format!("/hooks/{}", id).as_str()).as_str(),
+                    &options,
+                )
+                .await?;
+            crate::print_success!("Edited webhook {} in {}", hook.id, scope.name());
+            Ok(())
+        }
+        HookCommands::Delete {
+            scope_args,
+            id,
+            yes,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            crate::print_info!("Deleting webhook {} from {}", id, scope.name());
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(scope.api_path(format!("/hooks/{}", id).as_str()).as_str())
+                .await?;
+            crate::print_success!("Deleted webhook {} from {}", id, scope.name());
+            Ok(())
+        }
+        HookCommands::Test { repo_args, id } => {
+            // Gitea can only test repository webhooks
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            gitea_client
+                .post_empty(
+                    repo_context
+                        .api_path(format!("/hooks/{}/tests", id).as_str())
+                        .as_str(),
+                    &serde_json::json!({}),
+                )
+                .await?;
+            crate::print_success!(
+                "Queued a test delivery to webhook {} of {}",
+                id,
+                repo_context.repo()
+            );
+            Ok(())
+        }
+    }
+}
+
+fn hook_type_str(hook_type: &HookType) -> &'static str {
+    match hook_type {
+        HookType::Gitea => "gitea",
+        HookType::Gogs => "gogs",
+        HookType::Slack => "slack",
+        HookType::Discord => "discord",
+        HookType::Dingtalk => "dingtalk",
+        HookType::Telegram => "telegram",
+        HookType::Msteams => "msteams",
+        HookType::Feishu => "feishu",
+        HookType::Matrix => "matrix",
+        HookType::Wechatwork => "wechatwork",
+        HookType::Packagist => "packagist",
+    }
+}
+
+// Only the specified settings are included, so editing leaves the others alone
+fn config<'a>(
+    url: &Option<String>,
+    content_type: &Option<ContentType>,
+    secret: &Option<String>,
+    channel: &Option<String>,
+) -> HashMap<&'a str, String> {
+    let mut config = HashMap::new();
+    if let Some(url) = url {
+        config.insert("url", url.to_owned());
+    }
+    if let Some(content_type) = content_type {
+        let content_type = match content_type {
+            ContentType::Json => "json",
+            ContentType::Form => "form",
+        };
+        config.insert("content_type", content_type.to_string());
+    }
+    if let Some(secret) = secret {
+        config.insert("secret", secret.to_owned());
+    }
+    if let Some(channel) = channel {
+        config.insert("channel", channel.to_owned());
+    }
+    config
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{app_error::AppError, command_line_arguments::ConnectionArgs, config::Config};
+use reqwest::{Certificate, Client, Identity, NoProxy, Proxy};
+use std::path::Path;
+use std::sync::{Once, OnceLock};
+use url::Url;
+
+// The connection options apply to whichever server the command talks to
+static CONNECTION_ARGS: OnceLock<ConnectionArgs> = OnceLock::new();
+
+static INSECURE_WARNING: Once = Once::new();
+
+pub(crate) fn set_connection_args(connection_args: &ConnectionArgs) {
+    let _ = CONNECTION_ARGS.set(connection_args.clone());
+}
+
+// An HTTP client for the Gitea server, with the TLS and proxy settings from
+// the command line, or else from the server's entry in the config file
+pub(crate) fn http_client(gitea_url: &Url) -> Result<Client, AppError> {
+    let config = Config::load()?;
+    let server = config.server(gitea_url).cloned().unwrap_or_default();
+    let args = CONNECTION_ARGS.get();
+    let option = |arg: Option<&Option<String>>, setting: Option<String>| {
+        arg.and_then(|arg| arg.clone()).or(setting)
+    };
+    let ca_cert = args
+        .and_then(|args| args.ca_cert.clone())
+        .or(server.ca_cert);
+    let client_cert = args
+        .and_then(|args| args.client_cert.clone())
+        .or(server.client_cert);
+    let client_key = args
+        .and_then(|args| args.client_key.clone())
+        .or(server.client_key);
+    let insecure = args.is_some_and(|args| args.insecure) || server.insecure;
+    let proxy = option(args.map(|args| &args.proxy), server.proxy);
+    let no_proxy = option(args.map(|args| &args.no_proxy), server.no_proxy);
+
+    let mut builder = Client::builder();
+    if let Some(ca_cert) = ca_cert {
+        let certificates = Certificate::from_pem_bundle(&read(&ca_cert)?).map_err(|error| {
+            AppError::from(format!("Could not read {}: {}", ca_cert.display(), error))
+        })?;
+        for certificate in certificates.into_iter() {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+    match (client_cert, client_key) {
+        (Some(client_cert), Some(client_key)) => {
+            let identity = Identity::from_pkcs8_pem(&read(&client_cert)?, &read(&client_key)?)
+                .map_err(|error| {
+                    AppError::from(format!(
+                        "Could not read {}: {}",
+                        client_cert.display(),
+                        error
+                    ))
+                })?;
+            builder = builder.identity(identity);
+        }
+        (None, None) => {}
+        _ => {
+            return Err(AppError::from(
+                "A client certificate needs both a certificate and a key file",
+            ))
+        }
+    }
+    if insecure {
+        INSECURE_WARNING.call_once(|| {
+            crate::print_error!(
+                "WARNING: Not verifying the certificate of {}. Anyone in between can read and change everything sent, including credentials!",
+                gitea_url
+            )
+        });
+        builder = builder
+            .danger_accept_invalid_certs(true)
+            .danger_accept_invalid_hostnames(true);
+    }
+    for proxy in proxies(proxy, no_proxy)?.into_iter() {
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build()?)
+}
+
+// Without any settings, reqwest uses the proxy environment variables itself.
+// Hosts to bypass the proxy for can only be added to the proxies explicitly,
+// so then the environment variables are read here.
+fn proxies(proxy: Option<String>, no_proxy: Option<String>) -> Result<Vec<Proxy>, AppError> {
+    let no_proxy_list = match &no_proxy {
+        Some(no_proxy) => NoProxy::from_string(no_proxy),
+        None => NoProxy::from_env(),
+    };
+    let proxies = match proxy {
+        Some(proxy) => vec![Proxy::all(proxy.as_str())?],
+        None if no_proxy.is_some() => {
+            let mut proxies = Vec::new();
+            if let Some(proxy) = env_var(&["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"])
+            {
+                proxies.push(Proxy::https(proxy.as_str())?);
+            }
+            if let Some(proxy) = env_var(&["HTTP_PROXY", "http_proxy", "ALL_PROXY", "all_proxy"]) {
+                proxies.push(Proxy::http(proxy.as_str())?);
+            }
+            proxies
+        }
+        None => Vec::new(),
+    };
+    Ok(proxies
+        .into_iter()
+        .map(|proxy| proxy.no_proxy(no_proxy_list.clone()))
+        .collect())
+}
+
+fn env_var(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, AppError> {
+    std::fs::read(path)
+        .map_err(|error| AppError::from(format!("Could not read {}: {}", path.display(), error)))
+}
@@ -0,0 +1,402 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{IssueCommands, IssueState},
+    editor::edit_text,
+    gitea_client::{authenticated_client, GiteaClient},
+    models::{Comment, Label, Milestone, User},
+    output::print_json,
+    prompt::prompt_for_line,
+    repo_context::RepoContext,
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct Issue {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    state: String,
+    user: User,
+    #[serde(default)]
+    labels: Vec<Label>,
+    #[serde(default)]
+    assignees: Option<Vec<User>>,
+    #[serde(default)]
+    milestone: Option<Milestone>,
+    #[serde(default)]
+    comments: u64,
+    html_url: String,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Serialize)]
+struct CreateIssueOption<'a> {
+    title: &'a str,
+    body: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assignees: &'a Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone: Option<u64>,
+}
+
+#[derive(Default, Serialize)]
+struct EditIssueOption<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct CreateCommentOption<'a> {
+    body: &'a str,
+}
+
+pub(crate) async fn issue(command: &IssueCommands) -> Result<(), AppError> {
+    match command {
+        IssueCommands::List {
+            repo_args,
+            state,
+            labels,
+            assignee,
+            milestone,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let mut query = vec![
+                ("type", String::from("issues")),
+                ("state", state_str(state)),
+            ];
+            if !labels.is_empty() {
+                query.push(("labels", labels.join(",")));
+            }
+            if let Some(assignee) = assignee {
+                query.push(("assigned_by", assignee.to_owned()));
+            }
+            if let Some(milestone) = milestone {
+                query.push(("milestones", milestone.to_owned()));
+            }
+            let issues = gitea_client
+                .get_all::<Issue>(repo_context.api_path("/issues").as_str(), &query)
+                .await?;
+            if *json {
+                print_json(&issues)
+            } else if issues.is_empty() {
+                crate::print_info!("No matches");
+                Ok(())
+            } else {
+                let mut table = Table::new(&["#", "State", "Labels", "Assignees", "Title"]);
+                for issue in issues.iter() {
+                    table.add_row(vec![
+                        issue.number.to_string(),
+                        issue.state.clone(),
+                        label_names(&issue.labels),
+                        assignee_logins(&issue.assignees),
+                        issue.title.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        IssueCommands::View {
+            repo_args,
+            number,
+            comments,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let issue = get_issue(&gitea_client, &repo_context, *number).await?;
+            // The comments endpoint isn't paginated; it returns them all at once
+            let issue_comments = if *comments {
+                gitea_client
+                    .get::<Vec<Comment>>(
+                        repo_context
+                            .api_path(format!("/issues/{}/comments", number).as_str())
+                            .as_str(),
+                        &[],
+                    )
+                    .await?
+            } else {
+                Vec::new()
+            };
+            if *json {
+                print_json(&serde_json::json!({
+                    "issue": issue,
+                    "comments": issue_comments,
+                }))
+            } else {
+                print_issue(&issue);
+                for comment in issue_comments.iter() {
+                    println!();
+                    println!("➖️{} commented {}", comment.user.login, comment.created_at);
+                    println!("{}", comment.body);
+                }
+                Ok(())
+            }
+        }
+        IssueCommands::Create {
+            repo_args,
+            title,
+            body,
+            assignees,
+            labels,
+            milestone,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let title = match title {
+                Some(title) => title.to_owned(),
+                None => prompt_for_line("Title")?,
+            };
+            if title.trim().is_empty() {
+                return Err(AppError::Canceled);
+            }
+            let body = match body {
+                Some(body) => body.to_owned(),
+                None => edit_text("")?,
+            };
+            let milestone = match milestone {
+                Some(milestone) => {
+                    Some(milestone_id(&gitea_client, &repo_context, milestone).await?)
+                }
+                None => None,
+            };
+            let options = CreateIssueOption {
+                title: title.as_str(),
+                body: body.as_str(),
+                assignees,
+                labels: label_ids(&gitea_client, &repo_context, labels).await?,
+                milestone,
+            };
+            let issue = gitea_client
+                .post::<_, Issue>(repo_context.api_path("/issues").as_str(), &options)
+                .await?;
+            crate::print_success!("Created issue #{}: {}", issue.number, issue.html_url);
+            Ok(())
+        }
+        IssueCommands::Close {
+            repo_args,
+            number,
+            comment,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            if let Some(comment) = comment {
+                add_comment(&gitea_client, &repo_context, *number, comment).await?;
+            }
+            let options = EditIssueOption {
+                state: Some("closed"),
+                ..Default::default()
+            };
+            let issue = edit_issue(&gitea_client, &repo_context, *number, &options).await?;
+            crate::print_success!("Closed issue #{}: {}", issue.number, issue.title);
+            Ok(())
+        }
+        IssueCommands::Reopen { repo_args, number } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = EditIssueOption {
+                state: Some("open"),
+                ..Default::default()
+            };
+            let issue = edit_issue(&gitea_client, &repo_context, *number, &options).await?;
+            crate::print_success!("Reopened issue #{}: {}", issue.number, issue.title);
+            Ok(())
+        }
+        IssueCommands::Comment {
+            repo_args,
+            number,
+            body,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let body = match body {
+                Some(body) => body.to_owned(),
+                None => edit_text("")?,
+            };
+            if body.is_empty() {
+                return Err(AppError::Canceled);
+            }
+            add_comment(&gitea_client, &repo_context, *number, body.as_str()).await?;
+            crate::print_success!("Commented on issue #{}", number);
+            Ok(())
+        }
+        IssueCommands::Edit {
+            repo_args,
+            number,
+            title,
+            body,
+            editor,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let body = if *editor {
+                let issue = get_issue(&gitea_client, &repo_context, *number).await?;
+                Some(edit_text(issue.body.as_str())?)
+            } else {
+                body.to_owned()
+            };
+            let options = EditIssueOption {
+                title: title.as_deref(),
+                body: body.as_deref(),
+                ..Default::default()
+            };
+            let issue = edit_issue(&gitea_client, &repo_context, *number, &options).await?;
+            crate::print_success!("Edited issue #{}: {}", issue.number, issue.title);
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn state_str(state: &IssueState) -> String {
+    match state {
+        IssueState::Open => "open",
+        IssueState::Closed => "closed",
+        IssueState::All => "all",
+    }
+    .to_string()
+}
+
+fn print_issue(issue: &Issue) {
+    println!("#{} {}", issue.number, issue.title);
+    println!("➖️State: {}", issue.state);
+    println!("➖️Author: {}", issue.user.login);
+    println!("➖️Labels: {}", label_names(&issue.labels));
+    println!("➖️Assignees: {}", assignee_logins(&issue.assignees));
+    if let Some(milestone) = &issue.milestone {
+        println!("➖️Milestone: {}", milestone.title);
+    }
+    println!("➖️Comments: {}", issue.comments);
+    println!("➖️Created: {}", issue.created_at);
+    println!("➖️Updated: {}", issue.updated_at);
+    println!("➖️URL: {}", issue.html_url);
+    if !issue.body.is_empty() {
+        println!();
+        println!("{}", issue.body);
+    }
+}
+
+fn label_names(labels: &[Label]) -> String {
+    labels
+        .iter()
+        .map(|label| label.name.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+fn assignee_logins(assignees: &Option<Vec<User>>) -> String {
+    match assignees {
+        Some(assignees) => assignees
+            .iter()
+            .map(|assignee| assignee.login.as_str())
+            .collect::<Vec<&str>>()
+            .join(", "),
+        None => String::new(),
+    }
+}
+
+async fn get_issue(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    number: u64,
+) -> Result<Issue, AppError> {
+    gitea_client
+        .get::<Issue>(
+            repo_context
+                .api_path(format!("/issues/{}", number).as_str())
+                .as_str(),
+            &[],
+        )
+        .await
+}
+
+async fn edit_issue(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    number: u64,
+    options: &EditIssueOption<'_>,
+) -> Result<Issue, AppError> {
+    gitea_client
+        .patch::<_, Issue>(
+            repo_context
+                .api_path(format!("/issues/{}", number).as_str())
+                .as_str(),
+            options,
+        )
+        .await
+}
+
+pub(crate) async fn add_comment(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    number: u64,
+    body: &str,
+) -> Result<(), AppError> {
+    gitea_client
+        .post::<_, Comment>(
+            repo_context
+                .api_path(format!("/issues/{}/comments", number).as_str())
+                .as_str(),
+            &CreateCommentOption { body },
+        )
+        .await?;
+    Ok(())
+}
+
+// The API identifies labels by ID, but users know them by name
+pub(crate) async fn label_ids(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    names: &[String],
+) -> Result<Vec<u64>, AppError> {
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+    let labels = gitea_client
+        .get_all::<Label>(repo_context.api_path("/labels").as_str(), &[])
+        .await?;
+    names
+        .iter()
+        .map(|name| {
+            labels
+                .iter()
+                .find(|label| label.name.eq_ignore_ascii_case(name))
+                .map(|label| label.id)
+                .ok_or_else(|| AppError::NotFound(format!("Label '{}' not found", name)))
+        })
+        .collect()
+}
+
+// The API identifies milestones by ID, but users know them by title
+pub(crate) async fn milestone_id(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    title: &str,
+) -> Result<u64, AppError> {
+    let milestones = gitea_client
+        .get_all::<Milestone>(
+            repo_context.api_path("/milestones").as_str(),
+            &[("state", String::from("all"))],
+        )
+        .await?;
+    milestones
+        .iter()
+        .find(|milestone| milestone.title.eq_ignore_ascii_case(title))
+        .map(|milestone| milestone.id)
+        .ok_or_else(|| AppError::NotFound(format!("Milestone '{}' not found", title)))
+}
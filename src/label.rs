@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::LabelCommands,
+    data_file::{read_data_file, write_data_file},
+    gitea_client::{authenticated_client, GiteaClient},
+    models::Label,
+    output::print_json,
+    prompt::confirm,
+    repo_context::{resolve_server, Scope},
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+// A label as it appears in a label file
+#[derive(Deserialize, Serialize)]
+struct LabelSpec {
+    name: String,
+    color: String,
+    #[serde(default)]
+    description: String,
+}
+
+#[derive(Serialize)]
+struct CreateLabelOption<'a> {
+    name: &'a str,
+    color: &'a str,
+    description: &'a str,
+}
+
+#[derive(Serialize)]
+struct EditLabelOption<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+pub(crate) async fn label(command: &LabelCommands) -> Result<(), AppError> {
+    match command {
+        LabelCommands::List { scope_args, json } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let labels = get_labels(&gitea_client, &scope).await?;
+            if *json {
+                print_json(&labels)
+            } else if labels.is_empty() {
+                crate::print_info!("{} has no labels", scope.name());
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Name", "Color", "Description"]);
+                for label in labels.iter() {
+                    table.add_row(vec![
+                        label.name.clone(),
+                        normalize_color(label.color.as_str()),
+                        label.description.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        LabelCommands::Create {
+            scope_args,
+            name,
+            color,
+            description,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let spec = LabelSpec {
+                name: name.to_owned(),
+                color: color.to_owned(),
+                description: description.clone().unwrap_or_default(),
+            };
+            create_label(&gitea_client, &scope, &spec).await?;
+            crate::print_success!("Created label {} in {}", name, scope.name());
+            Ok(())
+        }
+        LabelCommands::Edit {
+            scope_args,
+            name,
+            new_name,
+            color,
+            description,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let label = find_label(&gitea_client, &scope, name).await?;
+            let color = color.as_deref().map(normalize_color);
+            let options = EditLabelOption {
+                name: new_name.as_deref(),
+                color: color.as_deref(),
+                description: description.as_deref(),
+            };
+            edit_label(&gitea_client, &scope, label.id, &options).await?;
+            crate::print_success!("Edited label {} in {}", name, scope.name());
+            Ok(())
+        }
+        LabelCommands::Delete {
+            scope_args,
+            name,
+            yes,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let label = find_label(&gitea_client, &scope, name).await?;
+            crate::print_info!("Deleting label {} from {}", label.name, scope.name());
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            delete_label(&gitea_client, &scope, label.id).await?;
+            crate::print_success!("Deleted label {} from {}", label.name, scope.name());
+            Ok(())
+        }
+        LabelCommands::Export {
+            scope_args,
+            output,
+            format,
+        } => {
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let specs = get_labels(&gitea_client, &scope)
+                .await?
+                .into_iter()
+                .map(|label| LabelSpec {
+                    color: normalize_color(label.color.as_str()),
+                    name: label.name,
+                    description: label.description,
+                })
+                .collect::<Vec<LabelSpec>>();
+            write_data_file(&specs, output, format)?;
+            if let Some(output) = output {
+                crate::print_success!(
+                    "Exported {} labels from {} to {}",
+                    specs.len(),
+                    scope.name(),
+                    output.display()
+                );
+            }
+            Ok(())
+        }
+        LabelCommands::Import { scope_args, file } => {
+            let specs = read_data_file::<LabelSpec>(file, "labels")?;
+            let scope = Scope::resolve(scope_args)?;
+            let gitea_client = authenticated_client(scope.gitea_url()).await?;
+            let labels = get_labels(&gitea_client, &scope).await?;
+            let mut created = 0;
+            for spec in specs.iter() {
+                if find_by_name(&labels, spec.name.as_str()).is_none() {
+                    create_label(&gitea_client, &scope, spec).await?;
+                    crate::print_success!("Created label {}", spec.name);
+                    created += 1;
+                }
+            }
+            crate::print_info!("Created {} labels in {}", created, scope.name());
+            Ok(())
+        }
+        LabelCommands::Sync {
+            scope_args,
+            file,
+            targets,
+            keep_extra,
+            dry_run,
+        } => {
+            let specs = read_data_file::<LabelSpec>(file, "labels")?;
+            let scopes = if targets.is_empty() {
+                vec![Scope::resolve(scope_args)?]
+            } else {
+                let gitea_url = resolve_server(&scope_args.repo_args)?;
+                targets
+                    .iter()
+                    .map(|target| Scope::repo(gitea_url.clone(), target))
+                    .collect()
+            };
+            for scope in scopes.iter() {
+                let gitea_client = authenticated_client(scope.gitea_url()).await?;
+                sync_labels(&gitea_client, scope, &specs, *keep_extra, *dry_run).await?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// Makes the scope's labels match the specs, and reports what changed
+async fn sync_labels(
+    gitea_client: &GiteaClient,
+    scope: &Scope,
+    specs: &[LabelSpec],
+    keep_extra: bool,
+    dry_run: bool,
+) -> Result<(), AppError> {
+    let labels = get_labels(gitea_client, scope).await?;
+    let (mut added, mut changed, mut removed) = (0, 0, 0);
+    for spec in specs.iter() {
+        let color = normalize_color(spec.color.as_str());
+        match find_by_name(&labels, spec.name.as_str()) {
+            None => {
+                if !dry_run {
+                    create_label(gitea_client, scope, spec).await?;
+                }
+                crate::print_success!("{}: added {}", scope.name(), spec.name);
+                added += 1;
+            }
+            Some(label) => {
+                if normalize_color(label.color.as_str()) != color
+                    || label.description != spec.description
+                    || label.name != spec.name
+                {
+                    if !dry_run {
+                        let options = EditLabelOption {
+                            name: Some(spec.name.as_str()),
+                            color: Some(color.as_str()),
+                            description: Some(spec.description.as_str()),
+                        };
+                        edit_label(gitea_client, scope, label.id, &options).await?;
+                    }
+                    crate::print_success!("{}: changed {}", scope.name(), spec.name);
+                    changed += 1;
+                }
+            }
+        }
+    }
+    if !keep_extra {
+        for label in labels.iter() {
+            if !specs
+                .iter()
+                .any(|spec| spec.name.eq_ignore_ascii_case(label.name.as_str()))
+            {
+                if !dry_run {
+                    delete_label(gitea_client, scope, label.id).await?;
+                }
+                crate::print_success!("{}: removed {}", scope.name(), label.name);
+                removed += 1;
+            }
+        }
+    }
+    crate::print_info!(
+        "{}: {} added, {} changed, {} removed{}",
+        scope.name(),
+        added,
+        changed,
+        removed,
+        if dry_run { " (dry run)" } else { "" }
+    );
+    Ok(())
+}
+
+async fn get_labels(gitea_client: &GiteaClient, scope: &Scope) -> Result<Vec<Label>, AppError> {
+    gitea_client
+        .get_all::<Label>(scope.api_path("/labels").as_str(), &[])
+        .await
+}
+
+async fn find_label(
+    gitea_client: &GiteaClient,
+    scope: &Scope,
+    name: &str,
+) -> Result<Label, AppError> {
+    get_labels(gitea_client, scope)
+        .await?
+        .into_iter()
+        .find(|label| label.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            AppError::NotFound(format!("Label '{}' not found in {}", name, scope.name()))
+        })
+}
+
+fn find_by_name<'a>(labels: &'a [Label], name: &str) -> Option<&'a Label> {
+    labels
+        .iter()
+        .find(|label| label.name.eq_ignore_ascii_case(name))
+}
+
+async fn create_label(
+    gitea_client: &GiteaClient,
+    scope: &Scope,
+    spec: &LabelSpec,
+) -> Result<Label, AppError> {
+    let color = normalize_color(spec.color.as_str());
+    let options = CreateLabelOption {
+        name: spec.name.as_str(),
+        color: color.as_str(),
+        description: spec.description.as_str(),
+    };
+    gitea_client
+        .post::<_, Label>(scope.api_path("/labels").as_str(), &options)
+        .await
+}
+
+async fn edit_label(
+    gitea_client: &GiteaClient,
+    scope: &Scope,
+    id: u64,
+    options: &EditLabelOption<'_>,
+) -> Result<Label, AppError> {
+    gitea_client
+        .patch::<_, Label>(
+            scope.api_path(format!("/labels/{}", id).as_str()).as_str(),
+            options,
+        )
+        .await
+}
+
+async fn delete_label(gitea_client: &GiteaClient, scope: &Scope, id: u64) -> Result<(), AppError> {
+    gitea_client
+        .delete(scope.api_path(format!("/labels/{}", id).as_str()).as_str())
+        .await
+}
+
+// Depending on the version, Gitea may or may not include the '#'
+fn normalize_color(color: &str) -> String {
+    format!("#{}", color.trim().trim_start_matches('#').to_lowercase())
+}
@@ -3,113 +3,106 @@
  * Created 2024-03-13
  */
 
-use crate::app_error::AppError;
-use gitea_api::{GiteaApi, SearchReposResult};
+use crate::{
+    app_error::AppError,
+    credentials::token,
+    gitea_client::{resolve_gitea_url, GiteaClient},
+    models::Repository,
+    output::print_json,
+    table::Table,
+};
 use url::Url;
 
 // The list command doesn't prompt for missing options, but it will use the
 // value in the GITEA_URL environment variable if it exists.
-pub(crate) async fn list(gitea_url: &Option<Url>, filter: &Option<String>) -> Result<(), AppError> {
-    let gitea_url = match gitea_url {
-        Some(gitea_url) => gitea_url.to_owned(),
-        None => {
-            let gitea_url_str =
-                Box::new(std::env::var("GITEA_URL").unwrap_or_else(|_| String::new())).leak();
-            if let Ok(gitea_url) = Url::parse(gitea_url_str) {
-                gitea_url
-            } else {
-                // The url ParseError is not very meaningful
-                return Err(AppError::from("Missing or invalid Gitea URL"));
-            }
+pub(crate) async fn list(
+    gitea_url: &Option<Url>,
+    filter: &Option<String>,
+    topics: &[String],
+    show_topics: &bool,
+    json: &bool,
+) -> Result<(), AppError> {
+    let gitea_url = resolve_gitea_url(gitea_url)?;
+    // Anyone can search public repositories, but a token also finds private ones.
+    // A token that can't be had, e.g. with the wrong passphrase, isn't needed.
+    let gitea_client = match token(&gitea_url).await {
+        Ok(Some(token)) => GiteaClient::with_token(&gitea_url, token.as_str())?,
+        Ok(None) => GiteaClient::new(&gitea_url, None, None)?,
+        Err(error) => {
+            crate::print_info!("Listing public repositories only: {}", error);
+            GiteaClient::new(&gitea_url, None, None)?
         }
     };
-    // let list_parameters = ListParameters::new(gitea_url.clone(), filter.clone());
-    let gitea_api = GiteaApi::new(gitea_url.as_str(), None, None);
-    match gitea_api.search_repos(Option::from(filter)).await {
-        Ok(result) => {
-            if result.ok() {
-                if result.repositories().is_empty() {
-                    crate::print_info!("No matches");
-                    Ok(())
-                } else {
-                    let full_name_width = full_name_width(&result);
-                    let clone_url_width = clone_url_width(&result);
-                    let description_width = description_width(&result);
-                    println!(
-                        "{:<full_name_width$} {:<clone_url_width$} Description",
-                        "Name", "Clone URL"
-                    );
-                    println!(
-                        "{:=<full_name_width$} {:=<clone_url_width$} {:=<description_width$}",
-                        "", "", ""
-                    );
-                    for repository in result.repositories().iter() {
-                        println!(
-                            "{:<full_name_width$} {:<clone_url_width$} {}",
-                            repository.full_name, repository.clone_url, repository.description,
-                        );
-                    }
-                    Ok(())
-                }
-            } else {
-                Err(AppError::from("Failed to get repositories"))
+    let repositories = search(&gitea_client, filter, topics).await?;
+    if *json {
+        let repositories = repositories
+            .iter()
+            .map(|repository| {
+                serde_json::json!({
+                    "full_name": repository.full_name,
+                    "clone_url": repository.clone_url,
+                    "description": repository.description,
+                    "topics": repository.topics,
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+        print_json(&repositories)
+    } else if repositories.is_empty() {
+        crate::print_info!("No matches");
+        Ok(())
+    } else {
+        let mut table = if *show_topics {
+            Table::new(&["Name", "Clone URL", "Topics", "Description"])
+        } else {
+            Table::new(&["Name", "Clone URL", "Description"])
+        };
+        for repository in repositories.into_iter() {
+            let mut row = vec![repository.full_name, repository.clone_url];
+            if *show_topics {
+                row.push(repository.topics.join(","));
             }
+            row.push(repository.description);
+            table.add_row(row);
         }
-        Err(error) => Err(AppError::from(error)),
+        table.print();
+        Ok(())
     }
 }
 
-fn full_name_width(search_repos_result: &SearchReposResult) -> usize {
-    search_repos_result
-        .repositories()
-        .iter()
-        .fold(0, |acc, repository| {
-            let len = repository.full_name.len();
-            if len > acc {
-                len
-            } else {
-                acc
-            }
-        })
-        + 1
-}
-
-fn clone_url_width(search_repos_result: &SearchReposResult) -> usize {
-    search_repos_result
-        .repositories()
-        .iter()
-        .fold(0, |acc, repository| {
-            let len = repository.clone_url.len();
-            if len > acc {
-                len
-            } else {
-                acc
-            }
+// Gitea can search either names or topics, and only for one value. When
+// searching topics, the other topics and the name filter are checked here.
+async fn search(
+    gitea_client: &GiteaClient,
+    filter: &Option<String>,
+    topics: &[String],
+) -> Result<Vec<Repository>, AppError> {
+    let Some(topic) = topics.first() else {
+        let query = [("q", filter.clone().unwrap_or_default())];
+        return gitea_client.search_repos(&query).await;
+    };
+    let query = [("q", topic.to_owned()), ("topic", String::from("true"))];
+    let filter = filter.as_ref().map(|filter| filter.to_lowercase());
+    Ok(gitea_client
+        .search_repos::<Repository>(&query)
+        .await?
+        .into_iter()
+        .filter(|repository| {
+            topics.iter().all(|topic| {
+                repository
+                    .topics
+                    .iter()
+                    .any(|repository_topic| repository_topic.eq_ignore_ascii_case(topic))
+            })
         })
-        + 1
-}
-
-fn description_width(search_repos_result: &SearchReposResult) -> usize {
-    search_repos_result
-        .repositories()
-        .iter()
-        .fold(0, |acc, repository| {
-            let len = if repository.description.is_empty() {
-                "Description"
-            } else {
-                repository.description.split('\n').fold("", |acc, line| {
-                    if line.len() > acc.len() {
-                        line
-                    } else {
-                        acc
-                    }
-                })
-            }
-            .len();
-            if len > acc {
-                len
-            } else {
-                acc
-            }
+        .filter(|repository| match &filter {
+            Some(filter) => repository
+                .full_name
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_lowercase()
+                .contains(filter),
+            None => true,
         })
+        .collect())
 }
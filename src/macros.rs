@@ -25,4 +25,4 @@ macro_rules! print_error {
             let message = format!($($arg)*);
             eprintln!("💥 {message}")
         }};
-    }
\ No newline at end of file
+    }
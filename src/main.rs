@@ -3,17 +3,54 @@ use std::process::ExitCode;
 use clap::Parser;
 
 use crate::{
+    app_error::AppError,
     command_line_arguments::{CommandLineArguments, Commands},
     error_level::ErrorLevel,
 };
 
+mod access;
+mod api;
 mod app_error;
+mod auth;
+mod branch;
 mod browse;
 mod command_line_arguments;
+mod config;
 mod create;
+mod credentials;
+mod data_file;
+mod deploy_key;
+mod editor;
 mod error_level;
+mod git;
+mod gitea_client;
+mod hook;
+mod http_client;
+mod issue;
+mod label;
 mod list;
 mod macros;
+mod milestone;
+mod mirror;
+mod models;
+mod oauth;
+mod org;
+mod output;
+mod pr;
+mod prompt;
+mod protect;
+mod push_mirror;
+mod release;
+mod remote_url;
+mod repo_context;
+mod repo_name;
+mod status;
+mod table;
+mod tag;
+mod team;
+mod token;
+mod token_store;
+mod topics;
 
 #[tokio::main]
 async fn main() -> ExitCode {
@@ -23,41 +60,37 @@ async fn main() -> ExitCode {
             ExitCode::from(error.exit_code() as u8)
         }
         Ok(command_line_arguments) => {
+            credentials::set_token_option(&command_line_arguments.token);
+            http_client::set_connection_args(&command_line_arguments.connection_args);
             match &command_line_arguments.command {
                 Some(Commands::List {
-                         gitea_url,
-                         filter: contains,
-                     }) => {
-                    let error_level = if let Err(error) = list::list(gitea_url, contains).await {
-                        print_error!("Error: {}", error);
-                        ErrorLevel::Failure
-                    } else {
-                        ErrorLevel::Success
-                    };
-                    ExitCode::from(error_level)
-                }
-                Some(Commands::Browse { path, remote_name }) => {
-                    let error_level = if let Err(error) = browse::browse(path, remote_name) {
-                        print_error!("Error: {}", error);
-                        ErrorLevel::Failure
-                    } else {
-                        ErrorLevel::Success
-                    };
-                    ExitCode::from(error_level)
-                }
+                    gitea_url,
+                    filter: contains,
+                    topics,
+                    show_topics,
+                    json,
+                }) => exit_code(list::list(gitea_url, contains, topics, show_topics, json).await),
+                Some(Commands::Browse {
+                    repo_args,
+                    verify,
+                    print,
+                    copy,
+                    target,
+                }) => exit_code(browse::browse(repo_args, verify, print, copy, target).await),
                 Some(Commands::Create {
-                         path,
-                         gitea_url,
-                         remote_name,
-                         gitea_name,
-                         description,
-                         default_branch,
-                         not_private,
-                         private,
-                         not_template,
-                         template,
-                     }) => {
-                    let error_level = if let Err(error) = create::create(
+                    path,
+                    gitea_url,
+                    remote_name,
+                    gitea_name,
+                    description,
+                    default_branch,
+                    not_private,
+                    private,
+                    not_template,
+                    template,
+                    protection,
+                }) => exit_code(
+                    create::create(
                         path,
                         gitea_url,
                         gitea_name,
@@ -68,18 +101,48 @@ async fn main() -> ExitCode {
                         not_private,
                         template,
                         not_template,
+                        protection,
                     )
-                        .await
-                    {
-                        print_error!("Error: {}", error);
-                        ErrorLevel::Failure
-                    } else {
-                        ErrorLevel::Success
-                    };
-                    ExitCode::from(error_level)
+                    .await,
+                ),
+                Some(Commands::Mirror { command }) => exit_code(mirror::mirror(command).await),
+                Some(Commands::PushMirror { command }) => {
+                    exit_code(push_mirror::push_mirror(command).await)
+                }
+                Some(Commands::Issue { command }) => exit_code(issue::issue(command).await),
+                Some(Commands::Pr { command }) => exit_code(pr::pr(command).await),
+                Some(Commands::Release { command }) => exit_code(release::release(command).await),
+                Some(Commands::Label { command }) => exit_code(label::label(command).await),
+                Some(Commands::Milestone { command }) => {
+                    exit_code(milestone::milestone(command).await)
                 }
-                None => ExitCode::from(ErrorLevel::Failure),
+                Some(Commands::Hook { command }) => exit_code(hook::hook(command).await),
+                Some(Commands::DeployKey { command }) => {
+                    exit_code(deploy_key::deploy_key(command).await)
+                }
+                Some(Commands::Access { command }) => exit_code(access::access(command).await),
+                Some(Commands::Org { command }) => exit_code(org::org(command).await),
+                Some(Commands::Team { command }) => exit_code(team::team(command).await),
+                Some(Commands::Protect { command }) => exit_code(protect::protect(command).await),
+                Some(Commands::Branch { command }) => exit_code(branch::branch(command).await),
+                Some(Commands::Tag { command }) => exit_code(tag::tag(command).await),
+                Some(Commands::Topics { command }) => exit_code(topics::topics(command).await),
+                Some(Commands::Status { command }) => exit_code(status::status(command).await),
+                Some(Commands::Token { command }) => exit_code(token::token(command).await),
+                Some(Commands::Auth { command }) => exit_code(auth::auth(command).await),
+                Some(Commands::Api { api_args }) => exit_code(api::api(api_args).await),
+                None => ExitCode::from(ErrorLevel::Usage),
             }
         }
     }
 }
+
+fn exit_code(result: Result<(), AppError>) -> ExitCode {
+    let error_level = if let Err(error) = result {
+        print_error!("Error: {}", error);
+        ErrorLevel::from(&error)
+    } else {
+        ErrorLevel::Success
+    };
+    ExitCode::from(error_level)
+}
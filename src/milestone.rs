@@ -0,0 +1,260 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::MilestoneCommands,
+    gitea_client::{authenticated_client, GiteaClient},
+    issue::{milestone_id, state_str},
+    models::Milestone,
+    output::print_json,
+    prompt::confirm,
+    repo_context::RepoContext,
+    table::Table,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct CreateMilestoneOption<'a> {
+    title: &'a str,
+    description: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<String>,
+}
+
+#[derive(Default, Serialize)]
+struct EditMilestoneOption<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<&'a str>,
+}
+
+pub(crate) async fn milestone(command: &MilestoneCommands) -> Result<(), AppError> {
+    match command {
+        MilestoneCommands::List {
+            repo_args,
+            state,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let milestones = gitea_client
+                .get_all::<Milestone>(
+                    repo_context.api_path("/milestones").as_str(),
+                    &[("state", state_str(state))],
+                )
+                .await?;
+            if *json {
+                print_json(&milestones)
+            } else if milestones.is_empty() {
+                crate::print_info!("No matches");
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Title", "State", "Due", "Progress", "Description"]);
+                for milestone in milestones.iter() {
+                    table.add_row(vec![
+                        milestone.title.clone(),
+                        milestone.state.clone(),
+                        due_date(milestone),
+                        progress(milestone),
+                        milestone.description.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        MilestoneCommands::Create {
+            repo_args,
+            title,
+            description,
+            due,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = CreateMilestoneOption {
+                title: title.as_str(),
+                description: description.as_deref().unwrap_or_default(),
+                due_on: due_on(due)?,
+            };
+            let milestone = gitea_client
+                .post::<_, Milestone>(repo_context.api_path("/milestones").as_str(), &options)
+                .await?;
+            crate::print_success!(
+                "Created milestone {} in {}",
+                milestone.title,
+                repo_context.repo()
+            );
+            Ok(())
+        }
+        MilestoneCommands::Edit {
+            repo_args,
+            title,
+            new_title,
+            description,
+            due,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = EditMilestoneOption {
+                title: new_title.as_deref(),
+                description: description.as_deref(),
+                due_on: due_on(due)?,
+                ..Default::default()
+            };
+            let milestone = edit_milestone(&gitea_client, &repo_context, title, &options).await?;
+            crate::print_success!("Edited milestone {}", milestone.title);
+            Ok(())
+        }
+        MilestoneCommands::Close { repo_args, title } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = EditMilestoneOption {
+                state: Some("closed"),
+                ..Default::default()
+            };
+            let milestone = edit_milestone(&gitea_client, &repo_context, title, &options).await?;
+            crate::print_success!("Closed milestone {}", milestone.title);
+            Ok(())
+        }
+        MilestoneCommands::Reopen { repo_args, title } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = EditMilestoneOption {
+                state: Some("open"),
+                ..Default::default()
+            };
+            let milestone = edit_milestone(&gitea_client, &repo_context, title, &options).await?;
+            crate::print_success!("Reopened milestone {}", milestone.title);
+            Ok(())
+        }
+        MilestoneCommands::Delete {
+            repo_args,
+            title,
+            yes,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let id = milestone_id(&gitea_client, &repo_context, title).await?;
+            crate::print_info!("Deleting milestone {} from {}", title, repo_context.repo());
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(
+                    repo_context
+                        .api_path(format!("/milestones/{}", id).as_str())
+                        .as_str(),
+                )
+                .await?;
+            crate::print_success!("Deleted milestone {}", title);
+            Ok(())
+        }
+    }
+}
+
+async fn edit_milestone(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    title: &str,
+    options: &EditMilestoneOption<'_>,
+) -> Result<Milestone, AppError> {
+    let id = milestone_id(gitea_client, repo_context, title).await?;
+    gitea_client
+        .patch::<_, Milestone>(
+            repo_context
+                .api_path(format!("/milestones/{}", id).as_str())
+                .as_str(),
+            options,
+        )
+        .await
+}
+
+// Only the date part of the due date is interesting
+fn due_date(milestone: &Milestone) -> String {
+    match &milestone.due_on {
+        Some(due_on) => due_on.chars().take(10).collect(),
+        None => String::new(),
+    }
+}
+
+fn progress(milestone: &Milestone) -> String {
+    let total = milestone.open_issues + milestone.closed_issues;
+    if total == 0 {
+        String::from("no issues")
+    } else {
+        format!(
+            "{}/{} closed ({}%)",
+            milestone.closed_issues,
+            total,
+            milestone.closed_issues * 100 / total
+        )
+    }
+}
+
+// The due date may be a date (YYYY-MM-DD) or a full RFC 3339 timestamp
+fn due_on(due: &Option<String>) -> Result<Option<String>, AppError> {
+    match due {
+        None => Ok(None),
+        Some(due) if due.contains('T') => Ok(Some(due.to_owned())),
+        Some(due) => {
+            let parts = due.split('-').collect::<Vec<&str>>();
+            let valid = parts.len() == 3
+                && [4, 2, 2]
+                    .iter()
+                    .zip(parts.iter())
+                    .all(|(len, part)| part.len() == *len && part.parse::<u32>().is_ok());
+            if valid {
+                Ok(Some(format!("{}T23:59:59Z", due)))
+            } else {
+                Err(AppError::from(format!(
+                    "Invalid due date '{}', expected YYYY-MM-DD",
+                    due
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::due_on;
+
+    fn due(due: &str) -> Option<String> {
+        due_on(&Some(due.to_string())).ok().flatten()
+    }
+
+    #[test]
+    fn dates_are_due_at_the_end_of_the_day() {
+        assert_eq!(due("2026-10-18").as_deref(), Some("2026-10-18T23:59:59Z"));
+        assert!(due_on(&None).unwrap().is_none());
+    }
+
+    #[test]
+    fn timestamps_are_used_as_they_are() {
+        assert_eq!(
+            due("2026-10-18T12:00:00+02:00").as_deref(),
+            Some("2026-10-18T12:00:00+02:00")
+        );
+    }
+
+    #[test]
+    fn invalid_dates() {
+        for invalid in [
+            "2026-1-18",
+            "18-10-2026",
+            "2026-10-xx",
+            "2026/10/18",
+            "tomorrow",
+        ] {
+            assert!(due_on(&Some(invalid.to_string())).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::MirrorCommands,
+    gitea_client::{authenticated_client, resolve_gitea_url, GiteaClient},
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Serialize)]
+struct MigrateRepoOptions<'a> {
+    clone_addr: &'a str,
+    repo_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repo_owner: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mirror_interval: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auth_username: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auth_password: Option<&'a str>,
+    mirror: bool,
+    private: bool,
+    service: &'a str,
+}
+
+#[derive(Serialize)]
+struct EditMirrorOptions<'a> {
+    mirror_interval: &'a str,
+}
+
+#[derive(Deserialize)]
+struct MirrorRepository {
+    full_name: String,
+    clone_url: String,
+    #[serde(default)]
+    original_url: String,
+    #[serde(default)]
+    mirror_interval: String,
+    #[serde(default)]
+    mirror_updated: Option<String>,
+}
+
+pub(crate) async fn mirror(command: &MirrorCommands) -> Result<(), AppError> {
+    match command {
+        MirrorCommands::Create {
+            gitea_url,
+            clone_url,
+            owner,
+            gitea_name,
+            description,
+            interval,
+            private,
+            auth_username,
+            auth_password,
+        } => {
+            let gitea_client = authenticated_client(&resolve_gitea_url(gitea_url)?).await?;
+            let gitea_name = match gitea_name {
+                Some(gitea_name) => gitea_name.to_owned(),
+                None => name_from_clone_url(clone_url)?,
+            };
+            let options = MigrateRepoOptions {
+                clone_addr: clone_url.as_str(),
+                repo_name: gitea_name.as_str(),
+                repo_owner: owner.as_deref(),
+                description: description.as_deref(),
+                mirror_interval: interval.as_deref(),
+                auth_username: auth_username.as_deref(),
+                auth_password: auth_password.as_deref(),
+                mirror: true,
+                private: *private,
+                service: "git",
+            };
+            let repository = gitea_client
+                .post::<_, MirrorRepository>("repos/migrate", &options)
+                .await?;
+            crate::print_success!("Created mirror of {}: {}", clone_url, repository.clone_url);
+            Ok(())
+        }
+        MirrorCommands::Sync { gitea_url, repo } => {
+            let gitea_client = authenticated_client(&resolve_gitea_url(gitea_url)?).await?;
+            gitea_client
+                .post_empty(
+                    format!("repos/{}/{}/mirror-sync", repo.owner(), repo.name()).as_str(),
+                    &serde_json::json!({}),
+                )
+                .await?;
+            crate::print_success!("Requested sync of {}", repo);
+            Ok(())
+        }
+        MirrorCommands::List { gitea_url, filter } => {
+            let gitea_client = authenticated_client(&resolve_gitea_url(gitea_url)?).await?;
+            let mirrors = search_mirrors(&gitea_client, filter).await?;
+            if mirrors.is_empty() {
+                crate::print_info!("No matches");
+            } else {
+                let mut table = Table::new(&["Name", "Interval", "Last sync", "Original URL"]);
+                for mirror in mirrors.iter() {
+                    table.add_row(vec![
+                        mirror.full_name.clone(),
+                        mirror.mirror_interval.clone(),
+                        last_sync(mirror),
+                        mirror.original_url.clone(),
+                    ]);
+                }
+                table.print();
+            }
+            Ok(())
+        }
+        MirrorCommands::Edit {
+            gitea_url,
+            repo,
+            interval,
+        } => {
+            let gitea_client = authenticated_client(&resolve_gitea_url(gitea_url)?).await?;
+            let repository = gitea_client
+                .patch::<_, MirrorRepository>(
+                    format!("repos/{}/{}", repo.owner(), repo.name()).as_str(),
+                    &EditMirrorOptions {
+                        mirror_interval: interval.as_str(),
+                    },
+                )
+                .await?;
+            crate::print_success!(
+                "Sync interval of {} is now {}",
+                repository.full_name,
+                repository.mirror_interval
+            );
+            Ok(())
+        }
+    }
+}
+
+async fn search_mirrors(
+    gitea_client: &GiteaClient,
+    filter: &Option<String>,
+) -> Result<Vec<MirrorRepository>, AppError> {
+    let query = [
+        ("mode", String::from("mirror")),
+        ("q", filter.clone().unwrap_or_default()),
+    ];
+    gitea_client.search_repos(&query).await
+}
+
+// Gitea reports the zero time for mirrors that have never been synced
+fn last_sync(mirror: &MirrorRepository) -> String {
+    match &mirror.mirror_updated {
+        Some(mirror_updated) if !mirror_updated.starts_with("0001-01-01") => {
+            mirror_updated.to_owned()
+        }
+        _ => String::from("never"),
+    }
+}
+
+// The name of the mirror defaults to the last segment of the URL, without any .git suffix
+fn name_from_clone_url(clone_url: &Url) -> Result<String, AppError> {
+    let name = clone_url
+        .path_segments()
+        .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+        .map(|segment| segment.trim_end_matches(".git"))
+        .unwrap_or_default();
+    if name.is_empty() {
+        Err(AppError::from(format!(
+            "Could not get a repository name from '{}'",
+            clone_url
+        )))
+    } else {
+        Ok(name.to_string())
+    }
+}
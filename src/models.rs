@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+// Gitea API types shared by several commands. Only the fields gtrepo uses are included.
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct User {
+    pub login: String,
+    #[serde(default)]
+    pub full_name: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Label {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub color: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Milestone {
+    pub id: u64,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub open_issues: u64,
+    #[serde(default)]
+    pub closed_issues: u64,
+    #[serde(default)]
+    pub due_on: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Comment {
+    pub id: u64,
+    pub user: User,
+    #[serde(default)]
+    pub body: String,
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Repository {
+    pub full_name: String,
+    #[serde(default)]
+    pub description: String,
+    pub html_url: String,
+    pub clone_url: String,
+    #[serde(default)]
+    pub default_branch: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub topics: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Team {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub permission: String,
+}
+
+// Gitea sends null rather than an empty list in some places
+pub(crate) fn null_as_empty<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    Ok(Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default())
+}
@@ -0,0 +1,293 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    config::{Config, ServerConfig},
+    http_client::http_client,
+};
+use aes_gcm::aead::{rand_core::RngCore, OsRng};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use url::Url;
+
+// Logging in on the web uses Gitea's OAuth2 authorization code flow with PKCE.
+// gtrepo is a public client, so it has no secret; the code verifier proves
+// that whoever exchanges the code is whoever asked for it. The OAuth2
+// application must be registered in Gitea as a public client with the
+// redirect URI http://127.0.0.1/callback, or http://127.0.0.1:PORT/callback if
+// the Gitea version only accepts the exact port.
+
+#[derive(Deserialize)]
+pub(crate) struct OAuthToken {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct TokenRequest<'a> {
+    grant_type: &'a str,
+    client_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_verifier: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_uri: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct TokenError {
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+
+// Tokens are refreshed a little before they expire, so they don't expire mid-command
+const EXPIRY_MARGIN: u64 = 60;
+
+// How long to wait for the login in the browser, and for each request to the
+// loopback listener to arrive
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(300);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Port 0 listens on any free port
+pub(crate) async fn web_login(
+    gitea_url: &Url,
+    client_id: &str,
+    port: u16,
+) -> Result<OAuthToken, AppError> {
+    let code_verifier = random_string();
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+    let state = random_string();
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    let redirect_uri = format!(
+        "http://127.0.0.1:{}/callback",
+        listener.local_addr()?.port()
+    );
+
+    let mut authorize_url = oauth_url(gitea_url, "authorize")?;
+    authorize_url
+        .query_pairs_mut()
+        .append_pair("client_id", client_id)
+        .append_pair("redirect_uri", redirect_uri.as_str())
+        .append_pair("response_type", "code")
+        .append_pair("state", state.as_str())
+        .append_pair("code_challenge", code_challenge.as_str())
+        .append_pair("code_challenge_method", "S256");
+    crate::print_info!("Log in to Gitea in the browser, at {}", authorize_url);
+    if let Err(error) = open::that_detached(authorize_url.as_str()) {
+        crate::print_info!("Could not open a browser: {}", error);
+    }
+
+    // Waiting for the browser blocks, so it happens off the async runtime
+    let code = tokio::task::spawn_blocking(move || wait_for_code(listener, state.as_str()))
+        .await
+        .map_err(|error| AppError::from(error.to_string()))??;
+    request_token(
+        gitea_url,
+        &TokenRequest {
+            grant_type: "authorization_code",
+            client_id,
+            code: Some(code.as_str()),
+            code_verifier: Some(code_verifier.as_str()),
+            redirect_uri: Some(redirect_uri.as_str()),
+            refresh_token: None,
+        },
+    )
+    .await
+}
+
+// Gets a new token for the server in the config file, saves it, and returns it
+pub(crate) async fn refresh_token(gitea_url: &Url) -> Result<String, AppError> {
+    let mut config = Config::load()?;
+    let server = config.server_mut(gitea_url);
+    let (Some(client_id), Some(refresh_token)) =
+        (server.client_id.clone(), server.refresh_token.clone())
+    else {
+        // Nothing to refresh it with; the server will say if it has expired
+        return Ok(server.token.clone().unwrap_or_default());
+    };
+    let token = request_token(
+        gitea_url,
+        &TokenRequest {
+            grant_type: "refresh_token",
+            client_id: client_id.as_str(),
+            code: None,
+            code_verifier: None,
+            redirect_uri: None,
+            refresh_token: Some(refresh_token.as_str()),
+        },
+    )
+    .await
+    .map_err(|error| {
+        AppError::from(format!(
+            "Could not refresh the token for {} ({}); log in again with gtrepo auth login --web",
+            gitea_url, error
+        ))
+    })?;
+    set_token(server, &token);
+    config.save()?;
+    Ok(token.access_token)
+}
+
+pub(crate) fn set_token(server: &mut ServerConfig, token: &OAuthToken) {
+    server.token = Some(token.access_token.clone());
+    // Gitea may not issue a new refresh token, in which case the old one still works
+    if token.refresh_token.is_some() {
+        server.refresh_token = token.refresh_token.clone();
+    }
+    server.expires_at = token.expires_in.map(|expires_in| now() + expires_in);
+}
+
+pub(crate) fn expires_soon(server: &ServerConfig) -> bool {
+    match server.expires_at {
+        Some(expires_at) => now() + EXPIRY_MARGIN >= expires_at,
+        None => false,
+    }
+}
+
+async fn request_token(
+    gitea_url: &Url,
+    request: &TokenRequest<'_>,
+) -> Result<OAuthToken, AppError> {
+    let response = http_client(gitea_url)?
+        .post(oauth_url(gitea_url, "access_token")?)
+        .form(request)
+        .send()
+        .await?;
+    let status = response.status();
+    if status.is_success() {
+        Ok(response.json::<OAuthToken>().await?)
+    } else {
+        let text = response.text().await.unwrap_or_default();
+        let message = match serde_json::from_str::<TokenError>(text.as_str()) {
+            Ok(error) if error.error_description.is_empty() => error.error,
+            Ok(error) => format!("{}: {}", error.error, error.error_description),
+            Err(_) => text,
+        };
+        Err(AppError::Status(status, message))
+    }
+}
+
+fn oauth_url(gitea_url: &Url, endpoint: &str) -> Result<Url, AppError> {
+    let mut base_url = gitea_url.clone();
+    if !base_url.path().ends_with('/') {
+        base_url.set_path(format!("{}/", base_url.path()).as_str());
+    }
+    Ok(base_url.join(format!("login/oauth/{}", endpoint).as_str())?)
+}
+
+// Answers requests to the loopback listener until the browser is redirected to
+// the callback, and returns the authorization code from it. Anything else that
+// connects, e.g. a browser opening a connection ahead of time, is answered and
+// ignored.
+fn wait_for_code(listener: TcpListener, state: &str) -> Result<String, AppError> {
+    let deadline = Instant::now() + LOGIN_TIMEOUT;
+    listener.set_nonblocking(true)?;
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Some(result) = answer(stream, state) {
+                    return result;
+                }
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                if Instant::now() >= deadline {
+                    return Err(AppError::from(
+                        "Timed out waiting for the login in the browser",
+                    ));
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Err(error) => return Err(AppError::from(error)),
+        }
+    }
+}
+
+// The result of the login, or None if the request wasn't the callback for it
+fn answer(mut stream: TcpStream, state: &str) -> Option<Result<String, AppError>> {
+    // A connection that never sends anything mustn't hold up the login
+    stream.set_nonblocking(false).ok()?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT)).ok()?;
+    let mut request_line = String::new();
+    // e.g. GET /callback?code=...&state=... HTTP/1.1
+    let target = match BufReader::new(&stream).read_line(&mut request_line) {
+        Ok(_) => request_line.split_whitespace().nth(1).unwrap_or_default(),
+        Err(_) => "",
+    };
+    let url = match Url::parse(format!("http://127.0.0.1{}", target).as_str()) {
+        Ok(url) if target.starts_with('/') && url.path() == "/callback" => url,
+        Ok(_) if target.starts_with('/') => {
+            respond(&mut stream, "404 Not Found", "");
+            return None;
+        }
+        _ => {
+            respond(&mut stream, "400 Bad Request", "");
+            return None;
+        }
+    };
+    let query = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.to_string())
+    };
+    // Only the browser that was sent to log in knows the state
+    if query("state").as_deref() != Some(state) {
+        respond(
+            &mut stream,
+            "400 Bad Request",
+            "The login response doesn't match the request.",
+        );
+        return None;
+    }
+    let result = if let Some(error) = query("error") {
+        Err(AppError::from(format!(
+            "Gitea refused the login: {}",
+            query("error_description").unwrap_or(error)
+        )))
+    } else {
+        query("code").ok_or_else(|| AppError::from("The login response has no code"))
+    };
+    let message = match &result {
+        Ok(_) => String::from("Logged in. You can close this window and go back to gtrepo."),
+        Err(error) => format!("Not logged in: {}", error),
+    };
+    respond(&mut stream, "200 OK", message.as_str());
+    Some(result)
+}
+
+// The browser only needs to see the message, so a failure to send it doesn't matter
+fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        message.len(),
+        message
+    );
+}
+
+fn random_string() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,185 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{OrgCommands, Visibility},
+    gitea_client::authenticated_client,
+    output::print_json,
+    repo_context::resolve_server_args,
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct Organization {
+    id: u64,
+    // The organization's name, which Gitea calls its user name
+    username: String,
+    #[serde(default)]
+    full_name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    website: String,
+    #[serde(default)]
+    location: String,
+    #[serde(default)]
+    visibility: String,
+}
+
+#[derive(Serialize)]
+struct CreateOrgOption<'a> {
+    username: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    website: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct EditOrgOption<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    website: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<&'static str>,
+}
+
+pub(crate) async fn org(command: &OrgCommands) -> Result<(), AppError> {
+    match command {
+        OrgCommands::List {
+            server_args,
+            all,
+            json,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let path = if *all { "orgs" } else { "user/orgs" };
+            let orgs = gitea_client.get_all::<Organization>(path, &[]).await?;
+            if *json {
+                print_json(&orgs)
+            } else if orgs.is_empty() {
+                crate::print_info!("No organizations");
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Name", "Visibility", "Full name", "Description"]);
+                for org in orgs.iter() {
+                    table.add_row(vec![
+                        org.username.clone(),
+                        org.visibility.clone(),
+                        org.full_name.clone(),
+                        org.description.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        OrgCommands::View {
+            server_args,
+            org,
+            json,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let org = gitea_client
+                .get::<Organization>(format!("orgs/{}", org).as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&org)
+            } else {
+                print_org(&org);
+                Ok(())
+            }
+        }
+        OrgCommands::Create {
+            server_args,
+            org,
+            full_name,
+            description,
+            website,
+            location,
+            visibility,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let options = CreateOrgOption {
+                username: org.as_str(),
+                full_name: full_name.as_deref(),
+                description: description.as_deref(),
+                website: website.as_deref(),
+                location: location.as_deref(),
+                visibility: visibility.as_ref().map(visibility_str),
+            };
+            let org = gitea_client
+                .post::<_, Organization>("orgs", &options)
+                .await?;
+            crate::print_success!("Created organization {}", org.username);
+            Ok(())
+        }
+        OrgCommands::Edit {
+            server_args,
+            org,
+            full_name,
+            description,
+            website,
+            location,
+            visibility,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let options = EditOrgOption {
+                full_name: full_name.as_deref(),
+                description: description.as_deref(),
+                website: website.as_deref(),
+                location: location.as_deref(),
+                visibility: visibility.as_ref().map(visibility_str),
+            };
+            let org = gitea_client
+                .patch::<_, Organization>(format!("orgs/{}", org).as_str(), &options)
+                .await?;
+            crate::print_success!("Edited organization {}", org.username);
+            Ok(())
+        }
+    }
+}
+
+fn visibility_str(visibility: &Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "public",
+        Visibility::Limited => "limited",
+        Visibility::Private => "private",
+    }
+}
+
+fn print_org(org: &Organization) {
+    println!("{}", org.username);
+    if !org.full_name.is_empty() {
+        println!("➖️Full name: {}", org.full_name);
+    }
+    println!("➖️Visibility: {}", org.visibility);
+    if !org.website.is_empty() {
+        println!("➖️Website: {}", org.website);
+    }
+    if !org.location.is_empty() {
+        println!("➖️Location: {}", org.location);
+    }
+    if !org.description.is_empty() {
+        println!();
+        println!("{}", org.description);
+    }
+}
@@ -0,0 +1,18 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::app_error::AppError;
+use serde::Serialize;
+
+// For commands that can print JSON instead of a table
+pub(crate) fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<(), AppError> {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => {
+            println!("{}", json);
+            Ok(())
+        }
+        Err(error) => Err(AppError::from(error.to_string())),
+    }
+}
@@ -0,0 +1,378 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{MergeStyle, PrCommands},
+    editor::edit_text,
+    git::{current_branch, git, git_interactive},
+    gitea_client::{authenticated_client, GiteaClient},
+    issue::{label_ids, milestone_id, state_str},
+    models::{Label, Repository, User},
+    output::print_json,
+    prompt::prompt_for_line,
+    repo_context::{local_path, RepoContext},
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct PullRequest {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    state: String,
+    user: User,
+    head: Branch,
+    base: Branch,
+    #[serde(default)]
+    labels: Vec<Label>,
+    #[serde(default)]
+    mergeable: bool,
+    #[serde(default)]
+    merged: bool,
+    html_url: String,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Branch {
+    #[serde(rename = "ref")]
+    ref_name: String,
+    #[serde(default)]
+    sha: String,
+}
+
+#[derive(Serialize)]
+struct CreatePullRequestOption<'a> {
+    head: &'a str,
+    base: &'a str,
+    title: &'a str,
+    body: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assignees: &'a Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct MergePullRequestOption<'a> {
+    #[serde(rename = "Do")]
+    merge_style: &'a str,
+    #[serde(rename = "MergeTitleField", skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(rename = "MergeMessageField", skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+    delete_branch_after_merge: bool,
+}
+
+#[derive(Serialize)]
+struct EditPullRequestOption<'a> {
+    state: &'a str,
+}
+
+pub(crate) async fn pr(command: &PrCommands) -> Result<(), AppError> {
+    match command {
+        PrCommands::List {
+            repo_args,
+            state,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let pull_requests = gitea_client
+                .get_all::<PullRequest>(
+                    repo_context.api_path("/pulls").as_str(),
+                    &[("state", state_str(state))],
+                )
+                .await?;
+            if *json {
+                print_json(&pull_requests)
+            } else if pull_requests.is_empty() {
+                crate::print_info!("No matches");
+                Ok(())
+            } else {
+                let mut table = Table::new(&["#", "State", "Branches", "Author", "Title"]);
+                for pull_request in pull_requests.iter() {
+                    table.add_row(vec![
+                        pull_request.number.to_string(),
+                        pull_request_state(pull_request),
+                        format!(
+                            "{} → {}",
+                            pull_request.head.ref_name, pull_request.base.ref_name
+                        ),
+                        pull_request.user.login.clone(),
+                        pull_request.title.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        PrCommands::View {
+            repo_args,
+            number,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let pull_request = get_pull_request(&gitea_client, &repo_context, *number).await?;
+            if *json {
+                print_json(&pull_request)
+            } else {
+                print_pull_request(&pull_request);
+                Ok(())
+            }
+        }
+        PrCommands::Create {
+            repo_args,
+            title,
+            body,
+            base,
+            head,
+            no_push,
+            assignees,
+            labels,
+            milestone,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let path = local_path(&repo_args.path);
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let head = match head {
+                Some(head) => head.to_owned(),
+                None => current_branch(&path)?,
+            };
+            let base = match base {
+                Some(base) => base.to_owned(),
+                None => {
+                    gitea_client
+                        .get::<Repository>(repo_context.api_path("").as_str(), &[])
+                        .await?
+                        .default_branch
+                }
+            };
+            if head == base {
+                return Err(AppError::from(format!(
+                    "The head and base branches are both '{}'",
+                    head
+                )));
+            }
+            let title = match title {
+                Some(title) => title.to_owned(),
+                None => prompt_for_line("Title")?,
+            };
+            if title.trim().is_empty() {
+                return Err(AppError::Canceled);
+            }
+            let body = match body {
+                Some(body) => body.to_owned(),
+                None => edit_text("")?,
+            };
+            if !*no_push {
+                git_interactive(
+                    &path,
+                    &["push", "-u", repo_args.remote_name.as_str(), head.as_str()],
+                )?;
+            }
+            let milestone = match milestone {
+                Some(milestone) => {
+                    Some(milestone_id(&gitea_client, &repo_context, milestone).await?)
+                }
+                None => None,
+            };
+            let options = CreatePullRequestOption {
+                head: head.as_str(),
+                base: base.as_str(),
+                title: title.as_str(),
+                body: body.as_str(),
+                assignees,
+                labels: label_ids(&gitea_client, &repo_context, labels).await?,
+                milestone,
+            };
+            let pull_request = gitea_client
+                .post::<_, PullRequest>(repo_context.api_path("/pulls").as_str(), &options)
+                .await?;
+            crate::print_success!(
+                "Created pull request #{}: {}",
+                pull_request.number,
+                pull_request.html_url
+            );
+            Ok(())
+        }
+        PrCommands::Checkout {
+            repo_args,
+            number,
+            branch,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let path = local_path(&repo_args.path);
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let pull_request = get_pull_request(&gitea_client, &repo_context, *number).await?;
+            // The pull request's own branch name could be one the user already has,
+            // e.g. main from a fork
+            let branch = match branch {
+                Some(branch) => branch.to_owned(),
+                None => format!("pr-{}", pull_request.number),
+            };
+            // Gitea publishes the head of every pull request as refs/pull/<number>/head,
+            // which works even if the branch is in a fork. Fetching it to FETCH_HEAD
+            // and resetting the branch to it works when the branch is checked out.
+            // An existing branch is only moved forward, so none of its commits are lost.
+            git_interactive(
+                &path,
+                &[
+                    "fetch",
+                    repo_args.remote_name.as_str(),
+                    format!("refs/pull/{}/head", number).as_str(),
+                ],
+            )?;
+            let local_ref = format!("refs/heads/{}", branch);
+            let exists = git(&path, &["rev-parse", "--verify", "--quiet", &local_ref]).is_ok();
+            if exists
+                && git(
+                    &path,
+                    &["merge-base", "--is-ancestor", &local_ref, "FETCH_HEAD"],
+                )
+                .is_err()
+            {
+                return Err(AppError::Git(format!(
+                    "Branch '{}' has commits that aren't in pull request #{}, use --branch to check it out as another branch",
+                    branch, pull_request.number
+                )));
+            }
+            git(&path, &["checkout", "-B", branch.as_str(), "FETCH_HEAD"])?;
+            crate::print_success!(
+                "Checked out pull request #{} as {}",
+                pull_request.number,
+                branch
+            );
+            Ok(())
+        }
+        PrCommands::Merge {
+            repo_args,
+            number,
+            style,
+            title,
+            message,
+            delete_branch,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = MergePullRequestOption {
+                merge_style: merge_style_str(style),
+                title: title.as_deref(),
+                message: message.as_deref(),
+                delete_branch_after_merge: *delete_branch,
+            };
+            gitea_client
+                .post_empty(
+                    repo_context
+                        .api_path(format!("/pulls/{}/merge", number).as_str())
+                        .as_str(),
+                    &options,
+                )
+                .await?;
+            crate::print_success!("Merged pull request #{}", number);
+            Ok(())
+        }
+        PrCommands::Close { repo_args, number } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let pull_request = gitea_client
+                .patch::<_, PullRequest>(
+                    repo_context
+                        .api_path(format!("/pulls/{}", number).as_str())
+                        .as_str(),
+                    &EditPullRequestOption { state: "closed" },
+                )
+                .await?;
+            crate::print_success!(
+                "Closed pull request #{}: {}",
+                pull_request.number,
+                pull_request.title
+            );
+            Ok(())
+        }
+        PrCommands::Diff { repo_args, number } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let diff = gitea_client
+                .get_text(
+                    repo_context
+                        .api_path(format!("/pulls/{}.diff", number).as_str())
+                        .as_str(),
+                )
+                .await?;
+            print!("{}", diff);
+            Ok(())
+        }
+    }
+}
+
+fn merge_style_str(style: &MergeStyle) -> &'static str {
+    match style {
+        MergeStyle::Merge => "merge",
+        MergeStyle::Rebase => "rebase",
+        MergeStyle::RebaseMerge => "rebase-merge",
+        MergeStyle::Squash => "squash",
+        MergeStyle::FastForward => "fast-forward-only",
+    }
+}
+
+// A closed pull request may have been merged
+fn pull_request_state(pull_request: &PullRequest) -> String {
+    if pull_request.merged {
+        String::from("merged")
+    } else {
+        pull_request.state.clone()
+    }
+}
+
+fn print_pull_request(pull_request: &PullRequest) {
+    println!("#{} {}", pull_request.number, pull_request.title);
+    println!("➖️State: {}", pull_request_state(pull_request));
+    println!("➖️Author: {}", pull_request.user.login);
+    println!(
+        "➖️Branches: {} → {}",
+        pull_request.head.ref_name, pull_request.base.ref_name
+    );
+    println!("➖️Head: {}", pull_request.head.sha);
+    println!("➖️Mergeable: {}", pull_request.mergeable);
+    println!(
+        "➖️Labels: {}",
+        pull_request
+            .labels
+            .iter()
+            .map(|label| label.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ")
+    );
+    println!("➖️Created: {}", pull_request.created_at);
+    println!("➖️Updated: {}", pull_request.updated_at);
+    println!("➖️URL: {}", pull_request.html_url);
+    if !pull_request.body.is_empty() {
+        println!();
+        println!("{}", pull_request.body);
+    }
+}
+
+async fn get_pull_request(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    number: u64,
+) -> Result<PullRequest, AppError> {
+    gitea_client
+        .get::<PullRequest>(
+            repo_context
+                .api_path(format!("/pulls/{}", number).as_str())
+                .as_str(),
+            &[],
+        )
+        .await
+}
@@ -0,0 +1,54 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::app_error::AppError;
+use std::io::{BufRead, Write};
+
+pub(crate) fn prompt_for_line(prompt: &str) -> Result<String, std::io::Error> {
+    let mut bucket = String::with_capacity(2048);
+    let mut stdin = std::io::stdin().lock();
+    let mut stdout = std::io::stdout().lock();
+
+    // We do this because stdout may be buffered
+    write!(stdout, "➕ {prompt}: ")?;
+    stdout.flush()?;
+
+    stdin.read_line(&mut bucket)?;
+    Ok(bucket
+        .trim_end_matches('\n')
+        .trim_end_matches('\r')
+        .to_string())
+}
+
+pub(crate) fn confirm() -> Result<bool, std::io::Error> {
+    let mut bucket = String::with_capacity(2048);
+    let mut stdin = std::io::stdin().lock();
+    let mut stdout = std::io::stdout().lock();
+
+    // We do this because stdout may be buffered
+    write!(stdout, "☑️ Continue? [Y/n]: ")?;
+    stdout.flush()?;
+
+    stdin.read_line(&mut bucket)?;
+    let result = bucket.trim_end_matches('\n').trim_end_matches('\r');
+    Ok(result.is_empty() || result.to_lowercase() == "y")
+}
+
+// Lists the choices with numbers, and asks for the number of one
+pub(crate) fn choose(prompt: &str, choices: &[String]) -> Result<usize, AppError> {
+    for (index, choice) in choices.iter().enumerate() {
+        println!("➖️{}. {}", index + 1, choice);
+    }
+    let answer = prompt_for_line(prompt)?;
+    let answer = answer.trim();
+    match answer.parse::<usize>() {
+        Ok(number) if number >= 1 && number <= choices.len() => Ok(number - 1),
+        _ if answer.is_empty() => Err(AppError::Canceled),
+        _ => Err(AppError::from(format!(
+            "'{}' is not one of the choices",
+            answer
+        ))),
+    }
+}
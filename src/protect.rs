@@ -0,0 +1,313 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{ProtectCommands, PushMode, RuleArgs},
+    create::bool_option,
+    data_file::{read_data_file, write_data_file},
+    gitea_client::{authenticated_client, path_segment, GiteaClient},
+    models::null_as_empty,
+    output::print_json,
+    prompt::confirm,
+    repo_context::{resolve_server, RepoContext},
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+// A branch protection rule, as the API returns it and as it appears in a rule file.
+// The same fields are used to create and edit rules.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct ProtectionRule {
+    rule_name: String,
+    required_approvals: i64,
+    enable_status_check: bool,
+    #[serde(deserialize_with = "null_as_empty")]
+    status_check_contexts: Vec<String>,
+    enable_push: bool,
+    enable_push_whitelist: bool,
+    #[serde(deserialize_with = "null_as_empty")]
+    push_whitelist_usernames: Vec<String>,
+    #[serde(deserialize_with = "null_as_empty")]
+    push_whitelist_teams: Vec<String>,
+    enable_merge_whitelist: bool,
+    #[serde(deserialize_with = "null_as_empty")]
+    merge_whitelist_usernames: Vec<String>,
+    #[serde(deserialize_with = "null_as_empty")]
+    merge_whitelist_teams: Vec<String>,
+    require_signed_commits: bool,
+    block_on_rejected_reviews: bool,
+}
+
+pub(crate) async fn protect(command: &ProtectCommands) -> Result<(), AppError> {
+    match command {
+        ProtectCommands::List { repo_args, json } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let rules = get_rules(&gitea_client, &repo_context).await?;
+            if *json {
+                print_json(&rules)
+            } else if rules.is_empty() {
+                crate::print_info!("{} has no protected branches", repo_context.repo());
+                Ok(())
+            } else {
+                let mut table = Table::new(&[
+                    "Pattern",
+                    "Approvals",
+                    "Push",
+                    "Merge",
+                    "Signed",
+                    "Block rejected",
+                    "Status checks",
+                ]);
+                for rule in rules.iter() {
+                    table.add_row(vec![
+                        rule.rule_name.clone(),
+                        rule.required_approvals.to_string(),
+                        push_description(rule),
+                        merge_description(rule),
+                        rule.require_signed_commits.to_string(),
+                        rule.block_on_rejected_reviews.to_string(),
+                        if rule.enable_status_check {
+                            rule.status_check_contexts.join(",")
+                        } else {
+                            String::new()
+                        },
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        ProtectCommands::Set {
+            repo_args,
+            pattern,
+            rule_args,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let existing = get_rules(&gitea_client, &repo_context)
+                .await?
+                .into_iter()
+                .find(|rule| rule.rule_name == *pattern);
+            let exists = existing.is_some();
+            let mut rule = existing.unwrap_or_else(|| ProtectionRule {
+                rule_name: pattern.to_owned(),
+                ..Default::default()
+            });
+            apply_args(&mut rule, rule_args);
+            save_rule(&gitea_client, &repo_context, &rule, exists).await?;
+            crate::print_success!(
+                "{} protection for {} in {}",
+                if exists { "Changed" } else { "Added" },
+                pattern,
+                repo_context.repo()
+            );
+            Ok(())
+        }
+        ProtectCommands::Remove {
+            repo_args,
+            pattern,
+            yes,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            crate::print_info!(
+                "Removing protection for {} from {}",
+                pattern,
+                repo_context.repo()
+            );
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(rule_path(&repo_context, pattern).as_str())
+                .await?;
+            crate::print_success!(
+                "Removed protection for {} from {}",
+                pattern,
+                repo_context.repo()
+            );
+            Ok(())
+        }
+        ProtectCommands::Export {
+            repo_args,
+            output,
+            format,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let rules = get_rules(&gitea_client, &repo_context).await?;
+            write_data_file(&rules, output, format)?;
+            if let Some(output) = output {
+                crate::print_success!(
+                    "Exported {} rules from {} to {}",
+                    rules.len(),
+                    repo_context.repo(),
+                    output.display()
+                );
+            }
+            Ok(())
+        }
+        ProtectCommands::Apply {
+            repo_args,
+            file,
+            targets,
+        } => {
+            let rules = read_rule_file(file)?;
+            let repo_contexts = if targets.is_empty() {
+                vec![RepoContext::resolve(repo_args)?]
+            } else {
+                let gitea_url = resolve_server(repo_args)?;
+                targets
+                    .iter()
+                    .map(|target| RepoContext::new(gitea_url.clone(), target))
+                    .collect()
+            };
+            for repo_context in repo_contexts.iter() {
+                let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+                apply_rules(&gitea_client, repo_context, &rules).await?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// Creates the rules, or changes them if the repository already has them
+pub(crate) async fn apply_rules(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    rules: &[ProtectionRule],
+) -> Result<(), AppError> {
+    let existing = get_rules(gitea_client, repo_context).await?;
+    for rule in rules.iter() {
+        let exists = existing
+            .iter()
+            .any(|existing| existing.rule_name == rule.rule_name);
+        save_rule(gitea_client, repo_context, rule, exists).await?;
+        crate::print_success!(
+            "{}: {} {}",
+            repo_context.repo(),
+            if exists { "changed" } else { "added" },
+            rule.rule_name
+        );
+    }
+    Ok(())
+}
+
+async fn get_rules(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+) -> Result<Vec<ProtectionRule>, AppError> {
+    gitea_client
+        .get::<Vec<ProtectionRule>>(repo_context.api_path("/branch_protections").as_str(), &[])
+        .await
+}
+
+async fn save_rule(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    rule: &ProtectionRule,
+    exists: bool,
+) -> Result<(), AppError> {
+    if exists {
+        gitea_client
+            .patch::<_, ProtectionRule>(
+                rule_path(repo_context, rule.rule_name.as_str()).as_str(),
+                rule,
+            )
+            .await?;
+    } else {
+        gitea_client
+            .post::<_, ProtectionRule>(repo_context.api_path("/branch_protections").as_str(), rule)
+            .await?;
+    }
+    Ok(())
+}
+
+fn rule_path(repo_context: &RepoContext, pattern: &str) -> String {
+    repo_context.api_path(format!("/branch_protections/{}", path_segment(pattern)).as_str())
+}
+
+fn apply_args(rule: &mut ProtectionRule, rule_args: &RuleArgs) {
+    if let Some(approvals) = rule_args.approvals {
+        rule.required_approvals = approvals;
+    }
+    if rule_args.no_status_checks {
+        rule.enable_status_check = false;
+        rule.status_check_contexts.clear();
+    } else if !rule_args.status_checks.is_empty() {
+        rule.enable_status_check = true;
+        rule.status_check_contexts = rule_args.status_checks.to_owned();
+    }
+    // Naming who can push implies a whitelist
+    let push = match &rule_args.push {
+        None if !rule_args.push_users.is_empty() || !rule_args.push_teams.is_empty() => {
+            Some(&PushMode::Whitelist)
+        }
+        push => push.as_ref(),
+    };
+    if let Some(push) = push {
+        rule.enable_push = !matches!(push, PushMode::None);
+        rule.enable_push_whitelist = matches!(push, PushMode::Whitelist);
+    }
+    if !rule_args.push_users.is_empty() {
+        rule.push_whitelist_usernames = rule_args.push_users.to_owned();
+    }
+    if !rule_args.push_teams.is_empty() {
+        rule.push_whitelist_teams = rule_args.push_teams.to_owned();
+    }
+    if rule_args.no_merge_whitelist {
+        rule.enable_merge_whitelist = false;
+        rule.merge_whitelist_usernames.clear();
+        rule.merge_whitelist_teams.clear();
+    } else if !rule_args.merge_users.is_empty() || !rule_args.merge_teams.is_empty() {
+        rule.enable_merge_whitelist = true;
+        rule.merge_whitelist_usernames = rule_args.merge_users.to_owned();
+        rule.merge_whitelist_teams = rule_args.merge_teams.to_owned();
+    }
+    if let Some(signed) = bool_option(&rule_args.not_signed, &rule_args.signed) {
+        rule.require_signed_commits = signed;
+    }
+    if let Some(block) = bool_option(
+        &rule_args.no_block_on_rejected,
+        &rule_args.block_on_rejected,
+    ) {
+        rule.block_on_rejected_reviews = block;
+    }
+}
+
+fn push_description(rule: &ProtectionRule) -> String {
+    if !rule.enable_push {
+        String::from("none")
+    } else if rule.enable_push_whitelist {
+        whitelist(&rule.push_whitelist_usernames, &rule.push_whitelist_teams)
+    } else {
+        String::from("all")
+    }
+}
+
+fn merge_description(rule: &ProtectionRule) -> String {
+    if rule.enable_merge_whitelist {
+        whitelist(&rule.merge_whitelist_usernames, &rule.merge_whitelist_teams)
+    } else {
+        String::from("all")
+    }
+}
+
+fn whitelist(usernames: &[String], teams: &[String]) -> String {
+    usernames
+        .iter()
+        .cloned()
+        .chain(teams.iter().map(|team| format!("team:{}", team)))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+pub(crate) fn read_rule_file(file: &Path) -> Result<Vec<ProtectionRule>, AppError> {
+    read_data_file(file, "rules")
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError, command_line_arguments::PushMirrorCommands,
+    gitea_client::authenticated_client, repo_context::RepoContext, table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+struct CreatePushMirrorOptions<'a> {
+    remote_address: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote_username: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote_password: Option<&'a str>,
+    interval: &'a str,
+    sync_on_commit: bool,
+}
+
+#[derive(Deserialize)]
+struct PushMirror {
+    remote_name: String,
+    remote_address: String,
+    #[serde(default)]
+    interval: String,
+    #[serde(default)]
+    sync_on_commit: bool,
+    #[serde(default)]
+    last_update: Option<String>,
+    #[serde(default)]
+    last_error: String,
+}
+
+pub(crate) async fn push_mirror(command: &PushMirrorCommands) -> Result<(), AppError> {
+    match command {
+        PushMirrorCommands::Add {
+            repo_args,
+            remote_address,
+            remote_username,
+            remote_password,
+            interval,
+            sync_on_commit,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = CreatePushMirrorOptions {
+                remote_address: remote_address.as_str(),
+                remote_username: remote_username.as_deref(),
+                remote_password: remote_password.as_deref(),
+                interval: interval.as_str(),
+                sync_on_commit: *sync_on_commit,
+            };
+            let push_mirror = gitea_client
+                .post::<_, PushMirror>(repo_context.api_path("/push_mirrors").as_str(), &options)
+                .await?;
+            crate::print_success!(
+                "Added push mirror {} of {}: {}",
+                push_mirror.remote_name,
+                repo_context.repo(),
+                push_mirror.remote_address
+            );
+            Ok(())
+        }
+        PushMirrorCommands::List { repo_args } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let push_mirrors = gitea_client
+                .get_all::<PushMirror>(repo_context.api_path("/push_mirrors").as_str(), &[])
+                .await?;
+            if push_mirrors.is_empty() {
+                crate::print_info!("{} has no push mirrors", repo_context.repo());
+            } else {
+                let mut table = Table::new(&[
+                    "Name",
+                    "Remote address",
+                    "Interval",
+                    "On commit",
+                    "Last update",
+                    "Last error",
+                ]);
+                for push_mirror in push_mirrors.iter() {
+                    table.add_row(vec![
+                        push_mirror.remote_name.clone(),
+                        push_mirror.remote_address.clone(),
+                        push_mirror.interval.clone(),
+                        push_mirror.sync_on_commit.to_string(),
+                        push_mirror.last_update.clone().unwrap_or_default(),
+                        push_mirror.last_error.trim().to_string(),
+                    ]);
+                }
+                table.print();
+                let failed = push_mirrors
+                    .iter()
+                    .filter(|push_mirror| !push_mirror.last_error.trim().is_empty())
+                    .count();
+                if failed > 0 {
+                    crate::print_error!("{} push mirror(s) failed their last update", failed);
+                }
+            }
+            Ok(())
+        }
+        PushMirrorCommands::Remove { repo_args, name } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            gitea_client
+                .delete(
+                    repo_context
+                        .api_path(format!("/push_mirrors/{}", name).as_str())
+                        .as_str(),
+                )
+                .await?;
+            crate::print_success!("Removed push mirror {} of {}", name, repo_context.repo());
+            Ok(())
+        }
+        PushMirrorCommands::Sync { repo_args } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            gitea_client
+                .post_empty(
+                    repo_context.api_path("/push_mirrors-sync").as_str(),
+                    &serde_json::json!({}),
+                )
+                .await?;
+            crate::print_success!(
+                "Requested sync of the push mirrors of {}",
+                repo_context.repo()
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,498 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::ReleaseCommands,
+    create::bool_option,
+    git::git,
+    gitea_client::{authenticated_client, path_segment, GiteaClient},
+    output::print_json,
+    prompt::confirm,
+    repo_context::{local_path, RepoContext},
+    table::Table,
+};
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+// The name of the attachment that holds the checksums of the other attachments,
+// in the format sha256sum uses
+const CHECKSUMS_NAME: &str = "SHA256SUMS";
+
+#[derive(Deserialize, Serialize)]
+struct Release {
+    id: u64,
+    tag_name: String,
+    #[serde(default)]
+    target_commitish: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    body: String,
+    draft: bool,
+    prerelease: bool,
+    created_at: String,
+    #[serde(default)]
+    published_at: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    assets: Vec<Attachment>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Attachment {
+    id: u64,
+    name: String,
+    size: u64,
+    #[serde(default)]
+    download_count: u64,
+    browser_download_url: String,
+}
+
+#[derive(Serialize)]
+struct CreateReleaseOption<'a> {
+    tag_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<&'a str>,
+    name: &'a str,
+    body: &'a str,
+    draft: bool,
+    prerelease: bool,
+}
+
+#[derive(Serialize)]
+struct EditReleaseOption<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<bool>,
+}
+
+pub(crate) async fn release(command: &ReleaseCommands) -> Result<(), AppError> {
+    match command {
+        ReleaseCommands::List { repo_args, json } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let releases = gitea_client
+                .get_all::<Release>(repo_context.api_path("/releases").as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&releases)
+            } else if releases.is_empty() {
+                crate::print_info!("{} has no releases", repo_context.repo());
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Tag", "Type", "Published", "Assets", "Title"]);
+                for release in releases.iter() {
+                    table.add_row(vec![
+                        release.tag_name.clone(),
+                        release_type(release),
+                        release.published_at.clone().unwrap_or_default(),
+                        release.assets.len().to_string(),
+                        release.name.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        ReleaseCommands::View {
+            repo_args,
+            tag,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let release = get_release(&gitea_client, &repo_context, tag).await?;
+            if *json {
+                print_json(&release)
+            } else {
+                print_release(&release);
+                Ok(())
+            }
+        }
+        ReleaseCommands::Create {
+            repo_args,
+            tag,
+            target,
+            title,
+            notes,
+            notes_file,
+            generate_notes,
+            draft,
+            prerelease,
+            files,
+            checksums,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let body = if *generate_notes {
+                generated_notes(&local_path(&repo_args.path), tag, target)?
+            } else {
+                notes_text(notes, notes_file)?.unwrap_or_default()
+            };
+            let options = CreateReleaseOption {
+                tag_name: tag.as_str(),
+                target_commitish: target.as_deref(),
+                name: title.as_deref().unwrap_or(tag.as_str()),
+                body: body.as_str(),
+                draft: *draft,
+                prerelease: *prerelease,
+            };
+            // Gitea creates the tag if it doesn't exist
+            let release = gitea_client
+                .post::<_, Release>(repo_context.api_path("/releases").as_str(), &options)
+                .await?;
+            crate::print_success!("Created release {}: {}", release.tag_name, release.html_url);
+            upload(&gitea_client, &repo_context, &release, files, *checksums).await
+        }
+        ReleaseCommands::Edit {
+            repo_args,
+            tag,
+            title,
+            notes,
+            notes_file,
+            draft,
+            not_draft,
+            prerelease,
+            not_prerelease,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let release = get_release(&gitea_client, &repo_context, tag).await?;
+            let body = notes_text(notes, notes_file)?;
+            let options = EditReleaseOption {
+                name: title.as_deref(),
+                body: body.as_deref(),
+                draft: bool_option(not_draft, draft),
+                prerelease: bool_option(not_prerelease, prerelease),
+            };
+            let release = gitea_client
+                .patch::<_, Release>(
+                    repo_context
+                        .api_path(format!("/releases/{}", release.id).as_str())
+                        .as_str(),
+                    &options,
+                )
+                .await?;
+            crate::print_success!("Edited release {}", release.tag_name);
+            Ok(())
+        }
+        ReleaseCommands::Delete {
+            repo_args,
+            tag,
+            delete_tag,
+            yes,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let release = get_release(&gitea_client, &repo_context, tag).await?;
+            crate::print_info!(
+                "Deleting release {} of {}{}",
+                release.tag_name,
+                repo_context.repo(),
+                if *delete_tag { " and its tag" } else { "" }
+            );
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(
+                    repo_context
+                        .api_path(format!("/releases/{}", release.id).as_str())
+                        .as_str(),
+                )
+                .await?;
+            crate::print_success!("Deleted release {}", release.tag_name);
+            if *delete_tag {
+                gitea_client
+                    .delete(
+                        repo_context
+                            .api_path(format!("/tags/{}", path_segment(&release.tag_name)).as_str())
+                            .as_str(),
+                    )
+                    .await?;
+                crate::print_success!("Deleted tag {}", release.tag_name);
+            }
+            Ok(())
+        }
+        ReleaseCommands::Upload {
+            repo_args,
+            tag,
+            files,
+            checksums,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let release = get_release(&gitea_client, &repo_context, tag).await?;
+            upload(&gitea_client, &repo_context, &release, files, *checksums).await
+        }
+        ReleaseCommands::Download {
+            repo_args,
+            tag,
+            names,
+            directory,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let release = get_release(&gitea_client, &repo_context, tag).await?;
+            download(&gitea_client, &release, names, directory).await
+        }
+    }
+}
+
+fn release_type(release: &Release) -> String {
+    if release.draft {
+        String::from("draft")
+    } else if release.prerelease {
+        String::from("prerelease")
+    } else {
+        String::from("release")
+    }
+}
+
+fn print_release(release: &Release) {
+    println!("{} {}", release.tag_name, release.name);
+    println!("➖️Type: {}", release_type(release));
+    println!("➖️Target: {}", release.target_commitish);
+    println!("➖️Created: {}", release.created_at);
+    if let Some(published_at) = &release.published_at {
+        println!("➖️Published: {}", published_at);
+    }
+    println!("➖️URL: {}", release.html_url);
+    for asset in release.assets.iter() {
+        println!(
+            "➖️Asset: {} ({} bytes, {} downloads)",
+            asset.name, asset.size, asset.download_count
+        );
+    }
+    if !release.body.is_empty() {
+        println!();
+        println!("{}", release.body);
+    }
+}
+
+async fn get_release(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    tag: &str,
+) -> Result<Release, AppError> {
+    gitea_client
+        .get::<Release>(
+            repo_context
+                .api_path(format!("/releases/tags/{}", path_segment(tag)).as_str())
+                .as_str(),
+            &[],
+        )
+        .await
+}
+
+// Release notes may be given as text or read from a file
+fn notes_text(
+    notes: &Option<String>,
+    notes_file: &Option<PathBuf>,
+) -> Result<Option<String>, AppError> {
+    match (notes, notes_file) {
+        (Some(notes), _) => Ok(Some(notes.to_owned())),
+        (None, Some(notes_file)) => Ok(Some(std::fs::read_to_string(notes_file)?)),
+        (None, None) => Ok(None),
+    }
+}
+
+// Lists the subjects of the commits since the previous tag
+fn generated_notes(path: &Path, tag: &str, target: &Option<String>) -> Result<String, AppError> {
+    let target = target.as_deref().unwrap_or("HEAD");
+    let exclude = format!("--exclude={}", tag);
+    let range = match git(
+        path,
+        &["describe", "--tags", "--abbrev=0", exclude.as_str(), target],
+    ) {
+        Ok(previous_tag) => format!("{}..{}", previous_tag, target),
+        // There is no previous tag, so use the whole history
+        Err(_) => target.to_string(),
+    };
+    let log = git(path, &["log", "--format=- %s (%h)", range.as_str()])?;
+    Ok(if log.is_empty() {
+        String::new()
+    } else {
+        format!("## Changes\n\n{}\n", log)
+    })
+}
+
+async fn upload(
+    gitea_client: &GiteaClient,
+    repo_context: &RepoContext,
+    release: &Release,
+    files: &[PathBuf],
+    checksums: bool,
+) -> Result<(), AppError> {
+    let assets_path = repo_context.api_path(format!("/releases/{}/assets", release.id).as_str());
+    let mut sums = String::new();
+    let mut names = Vec::new();
+    for file in files.iter() {
+        let name = file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| AppError::from(format!("Invalid file name: {}", file.display())))?
+            .to_string();
+        let contents = std::fs::read(file)?;
+        let sha256 = sha256(&contents);
+        sums.push_str(format!("{}  {}\n", sha256, name).as_str());
+        upload_attachment(gitea_client, assets_path.as_str(), name.as_str(), contents).await?;
+        crate::print_success!("Uploaded {} (sha256 {})", name, sha256);
+        names.push(name);
+    }
+    if checksums && !files.is_empty() {
+        // A release has one checksums file, so an earlier one is replaced,
+        // keeping the checksums of the assets that weren't uploaded again
+        if let Some(asset) = release
+            .assets
+            .iter()
+            .find(|asset| asset.name == CHECKSUMS_NAME)
+        {
+            let contents = gitea_client
+                .download(asset.browser_download_url.as_str())
+                .await?;
+            let mut earlier_sums = String::new();
+            for (sum, name) in parse_sums(String::from_utf8_lossy(&contents).as_ref()) {
+                if !names.contains(&name) {
+                    earlier_sums.push_str(format!("{}  {}\n", sum, name).as_str());
+                }
+            }
+            sums.insert_str(0, earlier_sums.as_str());
+            gitea_client
+                .delete(format!("{}/{}", assets_path, asset.id).as_str())
+                .await?;
+        }
+        upload_attachment(
+            gitea_client,
+            assets_path.as_str(),
+            CHECKSUMS_NAME,
+            sums.into_bytes(),
+        )
+        .await?;
+        crate::print_success!("Uploaded {}", CHECKSUMS_NAME);
+    }
+    Ok(())
+}
+
+async fn upload_attachment(
+    gitea_client: &GiteaClient,
+    assets_path: &str,
+    name: &str,
+    contents: Vec<u8>,
+) -> Result<Attachment, AppError> {
+    let form = Form::new().part(
+        "attachment",
+        Part::bytes(contents).file_name(name.to_string()),
+    );
+    gitea_client
+        .post_multipart::<Attachment>(assets_path, &[("name", name.to_string())], form)
+        .await
+}
+
+// Downloads the release's assets, and verifies them if the release has checksums
+async fn download(
+    gitea_client: &GiteaClient,
+    release: &Release,
+    names: &[String],
+    directory: &Option<PathBuf>,
+) -> Result<(), AppError> {
+    let directory = local_path(directory);
+    let expected_sums = match release
+        .assets
+        .iter()
+        .find(|asset| asset.name == CHECKSUMS_NAME)
+    {
+        Some(asset) => {
+            let contents = gitea_client
+                .download(asset.browser_download_url.as_str())
+                .await?;
+            parse_sums(String::from_utf8_lossy(&contents).as_ref())
+        }
+        None => Vec::new(),
+    };
+    let assets = release
+        .assets
+        .iter()
+        .filter(|asset| asset.name != CHECKSUMS_NAME)
+        .filter(|asset| names.is_empty() || names.contains(&asset.name))
+        .collect::<Vec<&Attachment>>();
+    if assets.is_empty() {
+        crate::print_info!("No assets to download");
+        return Ok(());
+    }
+    for asset in assets {
+        // The name comes from the server, so it mustn't lead out of the directory
+        let file_name = Path::new(asset.name.as_str())
+            .file_name()
+            .ok_or_else(|| AppError::from(format!("Invalid asset name: {}", asset.name)))?;
+        let contents = gitea_client
+            .download(asset.browser_download_url.as_str())
+            .await?;
+        let sha256 = sha256(&contents);
+        if let Some((expected, _)) = expected_sums.iter().find(|(_, name)| *name == asset.name) {
+            if *expected != sha256 {
+                return Err(AppError::from(format!(
+                    "Checksum mismatch for {}: expected {}, got {}",
+                    asset.name, expected, sha256
+                )));
+            }
+        }
+        std::fs::write(directory.join(file_name), &contents)?;
+        crate::print_success!(
+            "Downloaded {} (sha256 {})",
+            file_name.to_string_lossy(),
+            sha256
+        );
+    }
+    Ok(())
+}
+
+fn sha256(contents: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(contents))
+}
+
+// Each line is the checksum and the file name, separated by whitespace.
+// The name may be preceded by '*', meaning binary mode.
+fn parse_sums(text: &str) -> Vec<(String, String)> {
+    text.lines()
+        .filter_map(|line| {
+            let (sum, name) = line.split_once(char::is_whitespace)?;
+            Some((
+                sum.to_lowercase(),
+                name.trim().trim_start_matches('*').to_string(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_sums;
+
+    #[test]
+    fn sums_in_text_and_binary_mode() {
+        let text = "ABCDEF  gtrepo.tar.gz\n012345 *gtrepo.exe\n";
+        assert_eq!(
+            parse_sums(text),
+            vec![
+                (String::from("abcdef"), String::from("gtrepo.tar.gz")),
+                (String::from("012345"), String::from("gtrepo.exe")),
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_without_a_name_are_skipped() {
+        assert_eq!(parse_sums("\nabcdef\n"), Vec::new());
+    }
+}
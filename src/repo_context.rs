@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{RepoArgs, ScopeArgs, ServerArgs},
+    gitea_client::resolve_gitea_url,
+    remote_url::RemoteUrl,
+    repo_name::RepoName,
+};
+use git_lib::GitLib;
+use std::path::PathBuf;
+use url::Url;
+
+// The Gitea server and repository a command acts on. Unless they are
+// specified, they are inferred from the URL of a remote of the local repository.
+pub(crate) struct RepoContext {
+    gitea_url: Url,
+    repo: RepoName,
+}
+
+impl RepoContext {
+    pub fn new(gitea_url: Url, repo: &RepoName) -> RepoContext {
+        RepoContext {
+            gitea_url,
+            repo: repo.clone(),
+        }
+    }
+    pub fn gitea_url(&self) -> &Url {
+        &self.gitea_url
+    }
+    pub fn repo(&self) -> &RepoName {
+        &self.repo
+    }
+
+    // The API path of the repository, followed by the given path
+    pub fn api_path(&self, path: &str) -> String {
+        format!("repos/{}/{}{}", self.repo.owner(), self.repo.name(), path)
+    }
+
+    pub fn resolve(repo_args: &RepoArgs) -> Result<RepoContext, AppError> {
+        if let (Some(repo), Ok(gitea_url)) =
+            (&repo_args.repo, resolve_gitea_url(&repo_args.gitea_url))
+        {
+            // No need to look at the local repository
+            return Ok(RepoContext {
+                gitea_url,
+                repo: repo.clone(),
+            });
+        }
+        let remote_url = remote_url(&repo_args.path, &repo_args.remote_name)?;
+        let repo = match &repo_args.repo {
+            Some(repo) => repo.clone(),
+            None => remote_url.repo_name().ok_or_else(|| {
+                AppError::from(format!(
+                    "Could not get the repository name from remote '{}'",
+                    repo_args.remote_name
+                ))
+            })?,
+        };
+        // The Gitea URL may be specified, or implied by an HTTP(S) remote.
+        // Otherwise, use the GITEA_URL environment variable.
+        let gitea_url = match (&repo_args.gitea_url, remote_url.gitea_url()) {
+            (Some(gitea_url), _) => gitea_url.to_owned(),
+            (None, Some(gitea_url)) => gitea_url,
+            (None, None) => resolve_gitea_url(&None)?,
+        };
+        Ok(RepoContext { gitea_url, repo })
+    }
+}
+
+// Some things, e.g. labels and webhooks, belong to either a repository or an organization
+pub(crate) struct Scope {
+    gitea_url: Url,
+    // The API path of the repository or organization
+    path: String,
+    // How the user knows the repository or organization
+    name: String,
+}
+
+impl Scope {
+    pub fn gitea_url(&self) -> &Url {
+        &self.gitea_url
+    }
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    // The API path of the repository or organization, followed by the given path
+    pub fn api_path(&self, path: &str) -> String {
+        format!("{}{}", self.path, path)
+    }
+
+    pub fn repo(gitea_url: Url, repo: &RepoName) -> Scope {
+        Scope::from(RepoContext::new(gitea_url, repo))
+    }
+
+    pub fn resolve(scope_args: &ScopeArgs) -> Result<Scope, AppError> {
+        match &scope_args.org {
+            Some(org) => Ok(Scope {
+                gitea_url: resolve_server(&scope_args.repo_args)?,
+                path: format!("orgs/{}", org),
+                name: org.to_owned(),
+            }),
+            None => Ok(Scope::from(RepoContext::resolve(&scope_args.repo_args)?)),
+        }
+    }
+}
+
+impl From<RepoContext> for Scope {
+    fn from(repo_context: RepoContext) -> Scope {
+        Scope {
+            path: repo_context.api_path(""),
+            name: repo_context.repo().to_string(),
+            gitea_url: repo_context.gitea_url,
+        }
+    }
+}
+
+// The Gitea server, for commands that don't act on a particular repository.
+// It may be specified, or implied by the local repository's remote, or come from
+// the GITEA_URL environment variable.
+pub(crate) fn resolve_server(repo_args: &RepoArgs) -> Result<Url, AppError> {
+    server_url(
+        &repo_args.gitea_url,
+        &repo_args.path,
+        &repo_args.remote_name,
+    )
+}
+
+// The same, for commands that only take the server arguments
+pub(crate) fn resolve_server_args(server_args: &ServerArgs) -> Result<Url, AppError> {
+    server_url(
+        &server_args.gitea_url,
+        &server_args.path,
+        &server_args.remote_name,
+    )
+}
+
+pub(crate) fn local_path(path: &Option<PathBuf>) -> PathBuf {
+    match path {
+        Some(path) => path.to_owned(),
+        None => std::env::current_dir().unwrap_or_default(),
+    }
+}
+
+fn server_url(
+    gitea_url: &Option<Url>,
+    path: &Option<PathBuf>,
+    remote_name: &str,
+) -> Result<Url, AppError> {
+    if let Some(gitea_url) = gitea_url {
+        return Ok(gitea_url.to_owned());
+    }
+    match remote_url(path, remote_name)
+        .ok()
+        .and_then(|remote_url| remote_url.gitea_url())
+    {
+        Some(gitea_url) => Ok(gitea_url),
+        None => resolve_gitea_url(&None),
+    }
+}
+
+pub(crate) fn remote_url(path: &Option<PathBuf>, remote_name: &str) -> Result<RemoteUrl, AppError> {
+    let path = local_path(path);
+    match GitLib::remote_url(remote_name, Option::from(&path)) {
+        Ok(remote_url) => RemoteUrl::parse(remote_url.as_str()).ok_or_else(|| {
+            AppError::from(format!(
+                "Could not parse the URL of remote '{}': {}",
+                remote_name, remote_url
+            ))
+        }),
+        Err(error) => Err(AppError::Git(format!(
+            "Could not get remote URL for '{}': {}",
+            remote_name, error
+        ))),
+    }
+}
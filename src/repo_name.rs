@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// A repository's full name, i.e. "owner/name"
+#[derive(Clone, Debug)]
+pub struct RepoName {
+    owner: String,
+    name: String,
+}
+
+impl RepoName {
+    pub fn new(owner: &str, name: &str) -> RepoName {
+        RepoName {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        }
+    }
+    pub fn owner(&self) -> &String {
+        &self.owner
+    }
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+
+impl Display for RepoName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.owner, self.name)
+    }
+}
+
+impl FromStr for RepoName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+                Ok(RepoName::new(owner, name))
+            }
+            _ => Err(format!("'{}' is not in the form owner/name", s)),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::{CommitState, StatusCommands},
+    git::{resolve_commit, rev_parse},
+    gitea_client::{authenticated_client, path_segment},
+    output::print_json,
+    repo_context::{local_path, RepoContext},
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct CombinedStatus {
+    state: String,
+    sha: String,
+    #[serde(default)]
+    total_count: u64,
+    #[serde(default)]
+    statuses: Vec<CommitStatus>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CommitStatus {
+    // Older versions of Gitea call it status
+    #[serde(alias = "status")]
+    state: String,
+    #[serde(default)]
+    context: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    target_url: String,
+    #[serde(default)]
+    updated_at: String,
+}
+
+#[derive(Serialize)]
+struct CreateStatusOption<'a> {
+    state: &'static str,
+    context: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+pub(crate) async fn status(command: &StatusCommands) -> Result<(), AppError> {
+    match command {
+        StatusCommands::Set {
+            repo_args,
+            sha,
+            state,
+            context,
+            url,
+            description,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            // The status API only accepts SHAs
+            let sha = resolve_commit(
+                &local_path(&repo_args.path),
+                sha.as_deref().unwrap_or("HEAD"),
+            )?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = CreateStatusOption {
+                state: state_str(state),
+                context: context.as_deref().unwrap_or("default"),
+                target_url: url.as_ref().map(|url| url.as_str()),
+                description: description.as_deref(),
+            };
+            gitea_client
+                .post::<_, CommitStatus>(
+                    repo_context
+                        .api_path(format!("/statuses/{}", sha).as_str())
+                        .as_str(),
+                    &options,
+                )
+                .await?;
+            crate::print_success!(
+                "Set {} status of {} to {}",
+                options.context,
+                short_sha(&sha),
+                options.state
+            );
+            Ok(())
+        }
+        StatusCommands::Get {
+            repo_args,
+            reference,
+            json,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let reference = match reference {
+                Some(reference) => reference.to_owned(),
+                None => rev_parse(&local_path(&repo_args.path), "HEAD")?,
+            };
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let combined_status = gitea_client
+                .get::<CombinedStatus>(
+                    repo_context
+                        .api_path(format!("/commits/{}/status", path_segment(&reference)).as_str())
+                        .as_str(),
+                    &[],
+                )
+                .await?;
+            if *json {
+                return print_json(&combined_status);
+            }
+            if combined_status.total_count == 0 {
+                crate::print_info!("{} has no status", short_sha(&combined_status.sha));
+                return Ok(());
+            }
+            crate::print_info!(
+                "{} is {}",
+                short_sha(&combined_status.sha),
+                combined_status.state
+            );
+            let mut table = Table::new(&["Context", "State", "Updated", "Description", "URL"]);
+            for status in combined_status.statuses.into_iter() {
+                table.add_row(vec![
+                    status.context,
+                    status.state,
+                    status.updated_at,
+                    status.description,
+                    status.target_url,
+                ]);
+            }
+            table.print();
+            Ok(())
+        }
+    }
+}
+
+fn short_sha(sha: &str) -> String {
+    sha.chars().take(8).collect()
+}
+
+fn state_str(state: &CommitState) -> &'static str {
+    match state {
+        CommitState::Pending => "pending",
+        CommitState::Success => "success",
+        CommitState::Failure => "failure",
+        CommitState::Error => "error",
+        CommitState::Warning => "warning",
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+// Prints rows of values in columns, with a header row underlined with '='.
+// Every column but the last is padded to the width of its widest value.
+pub(crate) struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Table {
+        Table {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn print(&self) {
+        let widths = self.widths();
+        let last = self.headers.len() - 1;
+        let mut header = String::new();
+        let mut underline = String::new();
+        for (index, title) in self.headers.iter().enumerate() {
+            let width = widths[index];
+            if index == last {
+                header.push_str(title);
+                underline.push_str("=".repeat(width).as_str());
+            } else {
+                header.push_str(format!("{:<width$} ", title).as_str());
+                underline.push_str(format!("{:=<width$} ", "").as_str());
+            }
+        }
+        println!("{}", header);
+        println!("{}", underline);
+        for row in self.rows.iter() {
+            let mut line = String::new();
+            for (index, value) in row.iter().enumerate() {
+                if index == last {
+                    line.push_str(value);
+                } else {
+                    let width = widths[index];
+                    line.push_str(format!("{:<width$} ", value).as_str());
+                }
+            }
+            println!("{}", line);
+        }
+    }
+
+    // Prints the same rows as comma separated values, for spreadsheets and scripts
+    pub fn print_csv(&self) {
+        println!("{}", csv_line(&self.headers));
+        for row in self.rows.iter() {
+            println!("{}", csv_line(row));
+        }
+    }
+
+    // The width of a column is the length of its longest line
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(index, header)| {
+                self.rows.iter().fold(header.len(), |acc, row| {
+                    let len = row
+                        .get(index)
+                        .map(|value| value.split('\n').map(str::len).max().unwrap_or(0))
+                        .unwrap_or(0);
+                    if len > acc {
+                        len
+                    } else {
+                        acc
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+// Values containing a separator, quote or line break are quoted, with quotes doubled
+fn csv_line(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
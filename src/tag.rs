@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::TagCommands,
+    gitea_client::{authenticated_client, path_segment},
+    output::print_json,
+    prompt::confirm,
+    repo_context::RepoContext,
+    table::Table,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct Tag {
+    name: String,
+    #[serde(default)]
+    message: String,
+    commit: TagCommit,
+}
+
+#[derive(Deserialize, Serialize)]
+struct TagCommit {
+    sha: String,
+    #[serde(default)]
+    created: String,
+}
+
+#[derive(Serialize)]
+struct CreateTagOption<'a> {
+    tag_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+}
+
+pub(crate) async fn tag(command: &TagCommands) -> Result<(), AppError> {
+    match command {
+        TagCommands::List { repo_args, json } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let tags = gitea_client
+                .get_all::<Tag>(repo_context.api_path("/tags").as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&tags)
+            } else if tags.is_empty() {
+                crate::print_info!("{} has no tags", repo_context.repo());
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Name", "Date", "Commit", "Message"]);
+                for tag in tags.iter() {
+                    table.add_row(vec![
+                        tag.name.clone(),
+                        tag.commit.created.chars().take(10).collect(),
+                        tag.commit.sha.chars().take(8).collect(),
+                        tag.message.lines().next().unwrap_or_default().to_string(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        TagCommands::Create {
+            repo_args,
+            name,
+            target,
+            message,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let options = CreateTagOption {
+                tag_name: name.as_str(),
+                target: target.as_deref(),
+                message: message.as_deref(),
+            };
+            let tag = gitea_client
+                .post::<_, Tag>(repo_context.api_path("/tags").as_str(), &options)
+                .await?;
+            crate::print_success!(
+                "Created tag {} in {} at {}",
+                tag.name,
+                repo_context.repo(),
+                tag.commit.sha.chars().take(8).collect::<String>()
+            );
+            Ok(())
+        }
+        TagCommands::Delete {
+            repo_args,
+            names,
+            yes,
+        } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            crate::print_info!(
+                "Deleting tags {} from {}",
+                names.join(", "),
+                repo_context.repo()
+            );
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            for name in names.iter() {
+                gitea_client
+                    .delete(
+                        repo_context
+                            .api_path(format!("/tags/{}", path_segment(name)).as_str())
+                            .as_str(),
+                    )
+                    .await?;
+                crate::print_success!("Deleted tag {}", name);
+            }
+            Ok(())
+        }
+    }
+}
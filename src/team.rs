@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    access::permission_str,
+    app_error::AppError,
+    command_line_arguments::{Permission, TeamCommands},
+    gitea_client::{authenticated_client, GiteaClient},
+    models::{Team, User},
+    output::print_json,
+    prompt::confirm,
+    repo_context::resolve_server_args,
+    table::Table,
+};
+use serde::Serialize;
+
+// The parts of a repository a new team can use
+const UNITS: [&str; 7] = [
+    "repo.code",
+    "repo.issues",
+    "repo.pulls",
+    "repo.releases",
+    "repo.wiki",
+    "repo.projects",
+    "repo.packages",
+];
+
+#[derive(Serialize)]
+struct CreateTeamOption<'a> {
+    name: &'a str,
+    description: &'a str,
+    permission: &'static str,
+    includes_all_repositories: bool,
+    can_create_org_repo: bool,
+    units: &'a [&'a str],
+}
+
+pub(crate) async fn team(command: &TeamCommands) -> Result<(), AppError> {
+    match command {
+        TeamCommands::List {
+            server_args,
+            org,
+            json,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let teams = get_teams(&gitea_client, org).await?;
+            if *json {
+                print_json(&teams)
+            } else if teams.is_empty() {
+                crate::print_info!("{} has no teams", org);
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Name", "Permission", "Description"]);
+                for team in teams.iter() {
+                    table.add_row(vec![
+                        team.name.clone(),
+                        team.permission.clone(),
+                        team.description.clone(),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        TeamCommands::Create {
+            server_args,
+            org,
+            team,
+            description,
+            permission,
+            all_repos,
+            can_create_repos,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let options = CreateTeamOption {
+                name: team.as_str(),
+                description: description.as_deref().unwrap_or_default(),
+                permission: permission_str(permission.as_ref().unwrap_or(&Permission::Read)),
+                includes_all_repositories: *all_repos,
+                can_create_org_repo: *can_create_repos,
+                units: &UNITS,
+            };
+            let team = gitea_client
+                .post::<_, Team>(format!("orgs/{}/teams", org).as_str(), &options)
+                .await?;
+            crate::print_success!("Created team {} in {}", team.name, org);
+            Ok(())
+        }
+        TeamCommands::Members {
+            server_args,
+            org,
+            team,
+            json,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let team = find_team(&gitea_client, org, team).await?;
+            let members = gitea_client
+                .get_all::<User>(format!("teams/{}/members", team.id).as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&members)
+            } else if members.is_empty() {
+                crate::print_info!("{} has no members", team.name);
+                Ok(())
+            } else {
+                let mut table = Table::new(&["Login", "Full name"]);
+                for member in members.iter() {
+                    table.add_row(vec![member.login.clone(), member.full_name.clone()]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        TeamCommands::AddMember {
+            server_args,
+            org,
+            team,
+            users,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let team = find_team(&gitea_client, org, team).await?;
+            for user in users.iter() {
+                gitea_client
+                    .put(
+                        format!("teams/{}/members/{}", team.id, user).as_str(),
+                        &serde_json::json!({}),
+                    )
+                    .await?;
+                crate::print_success!("Added {} to {}", user, team.name);
+            }
+            Ok(())
+        }
+        TeamCommands::RemoveMember {
+            server_args,
+            org,
+            team,
+            users,
+            yes,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let team = find_team(&gitea_client, org, team).await?;
+            crate::print_info!("Removing {} from {}", users.join(", "), team.name);
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            for user in users.iter() {
+                gitea_client
+                    .delete(format!("teams/{}/members/{}", team.id, user).as_str())
+                    .await?;
+                crate::print_success!("Removed {} from {}", user, team.name);
+            }
+            Ok(())
+        }
+        TeamCommands::AddRepo {
+            server_args,
+            org,
+            team,
+            repos,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = authenticated_client(&gitea_url).await?;
+            let team = find_team(&gitea_client, org, team).await?;
+            for repo in repos.iter() {
+                gitea_client
+                    .put(
+                        format!("teams/{}/repos/{}/{}", team.id, org, repo).as_str(),
+                        &serde_json::json!({}),
+                    )
+                    .await?;
+                crate::print_success!("Gave {} access to {}/{}", team.name, org, repo);
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn get_teams(gitea_client: &GiteaClient, org: &str) -> Result<Vec<Team>, AppError> {
+    gitea_client
+        .get_all::<Team>(format!("orgs/{}/teams", org).as_str(), &[])
+        .await
+}
+
+// Team members and repositories are managed by team ID
+async fn find_team(gitea_client: &GiteaClient, org: &str, name: &str) -> Result<Team, AppError> {
+    get_teams(gitea_client, org)
+        .await?
+        .into_iter()
+        .find(|team| team.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| AppError::NotFound(format!("Team '{}' not found in {}", name, org)))
+}
@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    command_line_arguments::TokenCommands,
+    gitea_client::{password_client, path_segment, GiteaClient},
+    models::null_as_empty,
+    output::print_json,
+    prompt::confirm,
+    repo_context::resolve_server_args,
+    table::Table,
+    token_store::{store_file, store_token},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct AccessToken {
+    id: u64,
+    name: String,
+    #[serde(default, skip_serializing)]
+    sha1: String,
+    #[serde(default)]
+    token_last_eight: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    scopes: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CreateAccessTokenOption<'a> {
+    name: &'a str,
+    scopes: &'a Vec<String>,
+}
+
+// Gitea only lets a user manage their tokens with their username and password,
+// not with a token
+pub(crate) async fn token(command: &TokenCommands) -> Result<(), AppError> {
+    match command {
+        TokenCommands::List {
+            server_args,
+            otp,
+            json,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = password_client(&gitea_url)?.with_otp(otp);
+            let tokens = gitea_client
+                .get_all::<AccessToken>(tokens_path(&gitea_client)?.as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&tokens)
+            } else if tokens.is_empty() {
+                crate::print_info!("No access tokens");
+                Ok(())
+            } else {
+                let mut table = Table::new(&["ID", "Name", "Last eight", "Scopes"]);
+                for token in tokens.iter() {
+                    table.add_row(vec![
+                        token.id.to_string(),
+                        token.name.clone(),
+                        token.token_last_eight.clone(),
+                        token.scopes.join(","),
+                    ]);
+                }
+                table.print();
+                Ok(())
+            }
+        }
+        TokenCommands::Create {
+            server_args,
+            name,
+            scopes,
+            otp,
+            save,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = password_client(&gitea_url)?.with_otp(otp);
+            let options = CreateAccessTokenOption { name, scopes };
+            let token = gitea_client
+                .post::<_, AccessToken>(tokens_path(&gitea_client)?.as_str(), &options)
+                .await?;
+            crate::print_success!("Created access token {} ({})", token.name, token.id);
+            if *save {
+                store_token(&gitea_url, token.sha1.as_str())?;
+                crate::print_success!("Saved the token in {}", store_file()?.display());
+            } else {
+                // Gitea never shows the token again
+                crate::print_info!("Copy the token now, it can't be shown again");
+                println!("{}", token.sha1);
+            }
+            Ok(())
+        }
+        TokenCommands::Delete {
+            server_args,
+            name,
+            otp,
+            yes,
+        } => {
+            let gitea_url = resolve_server_args(server_args)?;
+            let gitea_client = password_client(&gitea_url)?.with_otp(otp);
+            crate::print_info!("Deleting access token {}", name);
+            if !*yes && !confirm()? {
+                return Err(AppError::Canceled);
+            }
+            gitea_client
+                .delete(format!("{}/{}", tokens_path(&gitea_client)?, path_segment(name)).as_str())
+                .await?;
+            crate::print_success!("Deleted access token {}", name);
+            Ok(())
+        }
+    }
+}
+
+fn tokens_path(gitea_client: &GiteaClient) -> Result<String, AppError> {
+    match gitea_client.username() {
+        Some(username) => Ok(format!("users/{}/tokens", path_segment(username))),
+        None => Err(AppError::from(
+            "The git credential helper has no username for the Gitea server",
+        )),
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError,
+    config::{config_dir, same_server, write_private},
+};
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use url::Url;
+
+// Tokens are kept in a file encrypted with a key derived from a passphrase, so
+// no OS keyring is needed. The passphrase comes from GTREPO_PASSPHRASE, or is
+// asked for once per run. The servers are listed in the clear, so the store is
+// only decrypted when it has a token for the server at hand.
+#[derive(Deserialize, Serialize)]
+struct StoreFile {
+    servers: Vec<String>,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+const SALT_LEN: usize = 16;
+
+static PASSPHRASE: OnceLock<String> = OnceLock::new();
+
+pub(crate) fn store_file() -> Result<PathBuf, AppError> {
+    Ok(config_dir()?.join("tokens"))
+}
+
+// The token stored for the server, if there is a store and it has one
+pub(crate) fn stored_token(gitea_url: &Url) -> Result<Option<String>, AppError> {
+    if !has_server(gitea_url)? {
+        return Ok(None);
+    }
+    let tokens = load()?;
+    Ok(tokens
+        .into_iter()
+        .find(|(key, _)| same_server(key, gitea_url))
+        .map(|(_, token)| token))
+}
+
+pub(crate) fn store_token(gitea_url: &Url, token: &str) -> Result<(), AppError> {
+    let mut tokens = if store_file()?.exists() {
+        load()?
+    } else {
+        BTreeMap::new()
+    };
+    tokens.retain(|key, _| !same_server(key, gitea_url));
+    tokens.insert(gitea_url.to_string(), token.to_string());
+    save(&tokens)
+}
+
+// Returns whether the store had a token for the server
+pub(crate) fn remove_token(gitea_url: &Url) -> Result<bool, AppError> {
+    if !has_server(gitea_url)? {
+        return Ok(false);
+    }
+    let mut tokens = load()?;
+    let count = tokens.len();
+    tokens.retain(|key, _| !same_server(key, gitea_url));
+    if tokens.len() == count {
+        return Ok(false);
+    }
+    save(&tokens)?;
+    Ok(true)
+}
+
+// The servers the store has tokens for
+pub(crate) fn stored_servers() -> Result<Vec<String>, AppError> {
+    if !store_file()?.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(read_store_file()?.servers)
+}
+
+// Whether the store has a token for the server, without decrypting it
+fn has_server(gitea_url: &Url) -> Result<bool, AppError> {
+    Ok(stored_servers()?
+        .iter()
+        .any(|key| same_server(key, gitea_url)))
+}
+
+fn read_store_file() -> Result<StoreFile, AppError> {
+    let path = store_file()?;
+    let text = std::fs::read_to_string(&path)?;
+    serde_json::from_str::<StoreFile>(text.as_str())
+        .map_err(|error| AppError::from(format!("Could not read {}: {}", path.display(), error)))
+}
+
+fn load() -> Result<BTreeMap<String, String>, AppError> {
+    let store_file = read_store_file()?;
+    let salt = decode(&store_file.salt)?;
+    let nonce = decode(&store_file.nonce)?;
+    let ciphertext = decode(&store_file.ciphertext)?;
+    if nonce.len() != 12 {
+        return Err(AppError::from("The token store is corrupt"));
+    }
+    let cipher = cipher(passphrase(false)?.as_str(), &salt)?;
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| AppError::from("Could not decrypt the token store; wrong passphrase?"))?;
+    serde_json::from_slice::<BTreeMap<String, String>>(&plaintext)
+        .map_err(|_| AppError::from("The token store is corrupt"))
+}
+
+// A new salt and nonce every time, so the same tokens never encrypt the same way twice
+fn save(tokens: &BTreeMap<String, String>) -> Result<(), AppError> {
+    let path = store_file()?;
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let cipher = cipher(passphrase(!path.exists())?.as_str(), &salt)?;
+    let plaintext =
+        serde_json::to_vec(tokens).map_err(|error| AppError::from(error.to_string()))?;
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_slice())
+        .map_err(|_| AppError::from("Could not encrypt the token store"))?;
+    let store_file = StoreFile {
+        servers: tokens.keys().cloned().collect(),
+        salt: STANDARD.encode(salt),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    };
+    let text = serde_json::to_string_pretty(&store_file)
+        .map_err(|error| AppError::from(error.to_string()))?;
+    write_private(&path, text.as_bytes())
+}
+
+fn cipher(passphrase: &str, salt: &[u8]) -> Result<Aes256Gcm, AppError> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|error| AppError::from(format!("Could not derive the key: {}", error)))?;
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)))
+}
+
+// A new store's passphrase is asked for twice, to catch typos
+fn passphrase(new: bool) -> Result<String, AppError> {
+    if let Some(passphrase) = PASSPHRASE.get() {
+        return Ok(passphrase.clone());
+    }
+    let passphrase = match std::env::var("GTREPO_PASSPHRASE") {
+        Ok(passphrase) if !passphrase.is_empty() => passphrase,
+        _ => {
+            let passphrase = rpassword::prompt_password("➕ Token store passphrase: ")?;
+            if new && rpassword::prompt_password("➕ Repeat the passphrase: ")? != passphrase {
+                return Err(AppError::from("The passphrases don't match"));
+            }
+            if passphrase.is_empty() {
+                return Err(AppError::Canceled);
+            }
+            passphrase
+        }
+    };
+    Ok(PASSPHRASE.get_or_init(|| passphrase).clone())
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, AppError> {
+    STANDARD
+        .decode(value)
+        .map_err(|_| AppError::from("The token store is corrupt"))
+}
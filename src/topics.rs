@@ -0,0 +1,91 @@
+/*
+ * Copyright (c) 2026 Paul Sobolik
+ * Created 2026-10-18
+ */
+
+use crate::{
+    app_error::AppError, command_line_arguments::TopicsCommands,
+    gitea_client::authenticated_client, output::print_json, repo_context::RepoContext,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+struct TopicNames {
+    topics: Vec<String>,
+}
+
+pub(crate) async fn topics(command: &TopicsCommands) -> Result<(), AppError> {
+    match command {
+        TopicsCommands::Get { repo_args, json } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            let topic_names = gitea_client
+                .get::<TopicNames>(repo_context.api_path("/topics").as_str(), &[])
+                .await?;
+            if *json {
+                print_json(&topic_names.topics)
+            } else if topic_names.topics.is_empty() {
+                crate::print_info!("{} has no topics", repo_context.repo());
+                Ok(())
+            } else {
+                for topic in topic_names.topics.iter() {
+                    println!("{}", topic);
+                }
+                Ok(())
+            }
+        }
+        TopicsCommands::Set { repo_args, topics } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            gitea_client
+                .put(
+                    repo_context.api_path("/topics").as_str(),
+                    &TopicNames {
+                        topics: topics.to_owned(),
+                    },
+                )
+                .await?;
+            if topics.is_empty() {
+                crate::print_success!("Removed the topics of {}", repo_context.repo());
+            } else {
+                crate::print_success!(
+                    "Set the topics of {} to {}",
+                    repo_context.repo(),
+                    topics.join(", ")
+                );
+            }
+            Ok(())
+        }
+        TopicsCommands::Add { repo_args, topics } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            for topic in topics.iter() {
+                gitea_client
+                    .put(
+                        repo_context
+                            .api_path(format!("/topics/{}", topic).as_str())
+                            .as_str(),
+                        &serde_json::json!({}),
+                    )
+                    .await?;
+                crate::print_success!("Added topic {} to {}", topic, repo_context.repo());
+            }
+            Ok(())
+        }
+        TopicsCommands::Remove { repo_args, topics } => {
+            let repo_context = RepoContext::resolve(repo_args)?;
+            let gitea_client = authenticated_client(repo_context.gitea_url()).await?;
+            for topic in topics.iter() {
+                gitea_client
+                    .delete(
+                        repo_context
+                            .api_path(format!("/topics/{}", topic).as_str())
+                            .as_str(),
+                    )
+                    .await?;
+                crate::print_success!("Removed topic {} from {}", topic, repo_context.repo());
+            }
+            Ok(())
+        }
+    }
+}